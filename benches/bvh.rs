@@ -0,0 +1,139 @@
+//! Casts the same rays at meshes of increasing size, with and without a
+//! bounding volume hierarchy. Run with `cargo bench --bench bvh`.
+//!
+//! Without a BVH the time per ray grows linearly with the triangle count;
+//! with one it should grow roughly logarithmically. The run fails if the
+//! BVH doesn't scale sub-linearly from the smallest mesh to the largest.
+
+use std::time::{Duration, Instant};
+
+use raytracer_rust::{
+    core::point::Point,
+    ray::Ray,
+    shapes::{group::Group, shape::Shape, triangle::Triangle},
+};
+
+const RAYS: usize = 2_000;
+
+/// Small deterministic generator, so every run measures the same scene.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> f64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn point(&mut self, scale: f64) -> Point {
+        Point::new(
+            (self.next() - 0.5) * scale,
+            (self.next() - 0.5) * scale,
+            (self.next() - 0.5) * scale,
+        )
+    }
+}
+
+/// `count` small triangles scattered through a 20 unit cube.
+fn mesh(count: usize) -> Group {
+    let mut rng = Lcg(count as u64);
+    let mut group = Group::new();
+
+    for _ in 0..count {
+        let center = rng.point(20.0);
+
+        group.add_child(Triangle::new(
+            center + (rng.point(0.5) - Point::new(0.0, 0.0, 0.0)),
+            center + (rng.point(0.5) - Point::new(0.0, 0.0, 0.0)),
+            center + (rng.point(0.5) - Point::new(0.0, 0.0, 0.0)),
+        ));
+    }
+
+    group
+}
+
+fn rays() -> Vec<Ray> {
+    let mut rng = Lcg(42);
+
+    (0..RAYS)
+        .map(|_| {
+            let target = rng.point(20.0);
+            let origin = Point::new(0.0, 0.0, -30.0);
+
+            Ray::from(origin, (target - origin).normalize())
+        })
+        .collect()
+}
+
+fn time_per_ray(group: &Group, rays: &[Ray]) -> (Duration, usize) {
+    let start = Instant::now();
+    let hits = rays
+        .iter()
+        .filter(|ray| group.intersect(ray).hit().is_some())
+        .count();
+
+    (start.elapsed() / rays.len() as u32, hits)
+}
+
+fn main() {
+    let rays = rays();
+
+    println!(
+        "{:>10} {:>12} {:>14} {:>14} {:>8}",
+        "triangles", "bvh build", "flat / ray", "bvh / ray", "speedup"
+    );
+
+    let counts = [1_000, 10_000, 100_000];
+    let mut flat_times = vec![];
+    let mut bvh_times = vec![];
+
+    for count in counts {
+        let flat = mesh(count);
+        let mut bvh = mesh(count);
+
+        let start = Instant::now();
+        bvh.build_bvh();
+        let build = start.elapsed();
+
+        let (flat_time, flat_hits) = time_per_ray(&flat, &rays);
+        let (bvh_time, bvh_hits) = time_per_ray(&bvh, &rays);
+
+        assert_eq!(
+            flat_hits, bvh_hits,
+            "the BVH changed which rays hit the mesh"
+        );
+
+        println!(
+            "{:>10} {:>12.2?} {:>14.2?} {:>14.2?} {:>7.1}x",
+            count,
+            build,
+            flat_time,
+            bvh_time,
+            flat_time.as_secs_f64() / bvh_time.as_secs_f64()
+        );
+
+        flat_times.push(flat_time.as_secs_f64());
+        bvh_times.push(bvh_time.as_secs_f64());
+    }
+
+    let triangles_growth = (counts[2] / counts[0]) as f64;
+    let flat_growth = flat_times[2] / flat_times[0];
+    let bvh_growth = bvh_times[2] / bvh_times[0];
+
+    println!(
+        "\n{triangles_growth}x the triangles: flat {flat_growth:.1}x, bvh {bvh_growth:.1}x the time per ray"
+    );
+
+    // timings are noisy, so only fail when the BVH is clearly not scaling
+    assert!(
+        bvh_growth < triangles_growth,
+        "BVH cost per ray grew {bvh_growth:.1}x for {triangles_growth}x the triangles"
+    );
+    assert!(
+        bvh_growth * 2.0 < flat_growth,
+        "BVH cost per ray grew {bvh_growth:.1}x, almost as fast as without one ({flat_growth:.1}x)"
+    );
+}
@@ -36,7 +36,7 @@ fn main() {
 
     while proj.position.y > 0.0 {
         let x = proj.position.x as usize;
-        let y = canvas.height - (proj.position.y as usize) - 1;
+        let y = canvas.height() - (proj.position.y as usize) - 1;
 
         canvas[[x, y]] = Color::RED;
 
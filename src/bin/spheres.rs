@@ -0,0 +1,91 @@
+use std::f64::consts::PI;
+
+use raytracer_rust::{
+    camera::Camera,
+    core::{color::Color, point::Point, transforms::Transforms, vector::Vector},
+    io::image::make_image,
+    lights::point_light::PointLight,
+    material::Material,
+    shapes::{shape::Shape, sphere::Sphere},
+    world::World,
+};
+
+fn main() {
+    let wall_material = Material {
+        specular: 0.0,
+        ..Material::with_color(Color::new(1.0, 0.9, 0.9))
+    };
+
+    let mut floor = Sphere::new();
+    floor.set_transform(Transforms::scaling(10.0, 0.01, 10.0));
+    floor.set_material(wall_material.clone());
+
+    let mut left_wall = Sphere::new();
+    left_wall.set_transform(
+        Transforms::translation(0.0, 0.0, 5.0)
+            * Transforms::rotation_y(-PI / 4.0)
+            * Transforms::rotation_x(PI / 2.0)
+            * Transforms::scaling(10.0, 0.01, 10.0),
+    );
+    left_wall.set_material(wall_material.clone());
+
+    let mut right_wall = Sphere::new();
+    right_wall.set_transform(
+        Transforms::translation(0.0, 0.0, 5.0)
+            * Transforms::rotation_y(PI / 4.0)
+            * Transforms::rotation_x(PI / 2.0)
+            * Transforms::scaling(10.0, 0.01, 10.0),
+    );
+    right_wall.set_material(wall_material);
+
+    let mut middle = Sphere::new();
+    middle.set_transform(Transforms::translation(-0.5, 1.0, 0.5));
+    middle.set_material(Material {
+        diffuse: 0.7,
+        specular: 0.3,
+        ..Material::with_color(Color::new(0.1, 1.0, 0.5))
+    });
+
+    let mut right = Sphere::new();
+    right.set_transform(
+        Transforms::translation(1.5, 0.5, -0.5) * Transforms::scaling(0.5, 0.5, 0.5),
+    );
+    right.set_material(Material {
+        diffuse: 0.7,
+        specular: 0.3,
+        ..Material::with_color(Color::new(0.5, 1.0, 0.1))
+    });
+
+    let mut left = Sphere::new();
+    left.set_transform(
+        Transforms::translation(-1.5, 0.33, -0.75) * Transforms::scaling(0.33, 0.33, 0.33),
+    );
+    left.set_material(Material {
+        diffuse: 0.7,
+        specular: 0.3,
+        ..Material::with_color(Color::new(1.0, 0.8, 0.1))
+    });
+
+    let mut world = World::new();
+    world.add_object(floor);
+    world.add_object(left_wall);
+    world.add_object(right_wall);
+    world.add_object(middle);
+    world.add_object(right);
+    world.add_object(left);
+    world.add_light(PointLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        Color::WHITE,
+    ));
+
+    let mut camera = Camera::new(400, 200, PI / 3.0);
+    camera.set_transform(Transforms::view_transform(
+        Point::new(0.0, 1.5, -5.0),
+        Point::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    ));
+
+    make_image(&camera.render(&world))
+        .save("img/spheres.png")
+        .expect("error saving image");
+}
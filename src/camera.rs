@@ -0,0 +1,314 @@
+use std::sync::Arc;
+
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+
+use crate::{
+    core::{canvas::Canvas, color::Color, matrix4::Matrix4, point::Point},
+    ray::Ray,
+    world::World,
+};
+
+/// Maps the pixels of a `hsize` x `vsize` canvas onto a view of the world.
+///
+/// The canvas sits one unit in front of the camera, which by default is at
+/// the origin looking towards negative z. The size and field of view are
+/// fixed once the camera is created, as the pixel size is worked out from
+/// them.
+#[derive(Debug, Clone)]
+pub struct Camera {
+    hsize: usize,
+    vsize: usize,
+    field_of_view: f64,
+    threads: Option<usize>,
+    pool: Option<Arc<ThreadPool>>,
+    transform: Matrix4,
+    inverse: Matrix4,
+    half_width: f64,
+    half_height: f64,
+    pixel_size: f64,
+}
+
+impl Camera {
+    pub fn new(hsize: usize, vsize: usize, field_of_view: f64) -> Self {
+        let half_view = (field_of_view / 2.0).tan();
+        let aspect = hsize as f64 / vsize as f64;
+
+        let (half_width, half_height) = if aspect >= 1.0 {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+
+        Camera {
+            hsize,
+            vsize,
+            field_of_view,
+            threads: None,
+            pool: None,
+            transform: Matrix4::IDENTITY,
+            inverse: Matrix4::IDENTITY,
+            half_width,
+            half_height,
+            pixel_size: half_width * 2.0 / hsize as f64,
+        }
+    }
+
+    pub fn hsize(&self) -> usize {
+        self.hsize
+    }
+
+    pub fn vsize(&self) -> usize {
+        self.vsize
+    }
+
+    pub fn field_of_view(&self) -> f64 {
+        self.field_of_view
+    }
+
+    pub fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.inverse = transform.inverse();
+    }
+
+    pub fn threads(&self) -> Option<usize> {
+        self.threads
+    }
+
+    /// Sets how many threads render the image. `None` uses one per core and
+    /// `Some(1)` renders on the calling thread; every setting produces the
+    /// exact same image.
+    ///
+    /// Any other count gets its own thread pool, built here once rather than
+    /// on every render.
+    ///
+    /// # Panics
+    ///
+    /// If `threads` is `Some(0)`, as nothing would render the image.
+    pub fn set_threads(&mut self, threads: Option<usize>) {
+        assert_ne!(threads, Some(0), "camera needs at least one render thread");
+
+        self.threads = threads;
+        self.pool = match threads {
+            Some(threads) if threads > 1 => Some(Arc::new(
+                ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .expect("error creating render threads"),
+            )),
+            _ => None,
+        };
+    }
+
+    pub fn pixel_size(&self) -> f64 {
+        self.pixel_size
+    }
+
+    /// A ray from the camera through the center of pixel (`x`, `y`).
+    pub fn ray_for_pixel(&self, x: usize, y: usize) -> Ray {
+        let xoffset = (x as f64 + 0.5) * self.pixel_size;
+        let yoffset = (y as f64 + 0.5) * self.pixel_size;
+
+        let world_x = self.half_width - xoffset;
+        let world_y = self.half_height - yoffset;
+
+        let pixel = self.inverse * Point::new(world_x, world_y, -1.0);
+        let origin = self.inverse * Point::new(0.0, 0.0, 0.0);
+        let direction = (pixel - origin).normalize();
+
+        Ray::from(origin, direction)
+    }
+
+    pub fn render(&self, world: &World) -> Canvas {
+        let mut image = Canvas::new(self.hsize, self.vsize);
+
+        match (self.threads, &self.pool) {
+            (Some(1), _) => self.render_serial(world, &mut image),
+            (_, Some(pool)) => pool.install(|| self.render_parallel(world, &mut image)),
+            (_, None) => self.render_parallel(world, &mut image),
+        }
+
+        image
+    }
+
+    fn render_serial(&self, world: &World, image: &mut Canvas) {
+        for (y, row) in image.rows_mut().enumerate() {
+            self.render_row(world, y, row);
+        }
+    }
+
+    /// Renders scanlines in parallel, each thread writing straight into its
+    /// own rows of the canvas. Pixels are shaded exactly as in the serial
+    /// path, which keeps the output identical.
+    fn render_parallel(&self, world: &World, image: &mut Canvas) {
+        image
+            .pixels_mut()
+            .par_chunks_mut(self.hsize.max(1))
+            .enumerate()
+            .for_each(|(y, row)| self.render_row(world, y, row));
+    }
+
+    fn render_row(&self, world: &World, y: usize, row: &mut [Color]) {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            *pixel = world.color_at(&self.ray_for_pixel(x, y));
+        }
+    }
+}
+
+#[cfg(test)]
+mod camera_tests {
+    use std::f64::consts::PI;
+
+    use float_cmp::assert_approx_eq;
+
+    use crate::{
+        core::{transforms::Transforms, vector::Vector},
+        lights::area_light::AreaLight,
+        utils::utils::EPSILON,
+    };
+
+    use super::*;
+
+    #[test]
+    fn constructing_camera() {
+        let c = Camera::new(160, 120, PI / 2.0);
+
+        assert_eq!(c.hsize(), 160);
+        assert_eq!(c.vsize(), 120);
+        assert_eq!(c.field_of_view(), PI / 2.0);
+        assert_eq!(*c.transform(), Matrix4::IDENTITY);
+    }
+
+    #[test]
+    fn pixel_size_for_horizontal_canvas() {
+        let c = Camera::new(200, 125, PI / 2.0);
+
+        assert_approx_eq!(f64, c.pixel_size(), 0.01, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn pixel_size_for_vertical_canvas() {
+        let c = Camera::new(125, 200, PI / 2.0);
+
+        assert_approx_eq!(f64, c.pixel_size(), 0.01, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn ray_through_center_of_canvas() {
+        let c = Camera::new(201, 101, PI / 2.0);
+
+        let r = c.ray_for_pixel(100, 50);
+
+        assert_eq!(r.origin, Point::new(0.0, 0.0, 0.0));
+        assert_eq!(r.direction, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn ray_through_corner_of_canvas() {
+        let c = Camera::new(201, 101, PI / 2.0);
+
+        let r = c.ray_for_pixel(0, 0);
+
+        assert_eq!(r.origin, Point::new(0.0, 0.0, 0.0));
+        assert_eq!(r.direction, Vector::new(0.66519, 0.33259, -0.66851));
+    }
+
+    #[test]
+    fn ray_when_camera_is_transformed() {
+        let mut c = Camera::new(201, 101, PI / 2.0);
+        c.set_transform(Transforms::rotation_y(PI / 4.0) * Transforms::translation(0.0, -2.0, 5.0));
+
+        let r = c.ray_for_pixel(100, 50);
+
+        assert_eq!(r.origin, Point::new(0.0, 2.0, -5.0));
+        assert_eq!(
+            r.direction,
+            Vector::new(2.0_f64.sqrt() / 2.0, 0.0, -2.0_f64.sqrt() / 2.0)
+        );
+    }
+
+    #[test]
+    fn rendering_world_with_camera() {
+        let w = World::default_scene();
+        let mut c = Camera::new(11, 11, PI / 2.0);
+        let from = Point::new(0.0, 0.0, -5.0);
+        let to = Point::new(0.0, 0.0, 0.0);
+        let up = Vector::new(0.0, 1.0, 0.0);
+        c.set_transform(Transforms::view_transform(from, to, up));
+
+        let image = c.render(&w);
+
+        assert_eq!(image[[5, 5]], Color::new(0.38066, 0.47583, 0.2855));
+    }
+
+    #[test]
+    fn camera_renders_on_every_core_by_default() {
+        let c = Camera::new(160, 120, PI / 2.0);
+
+        assert_eq!(c.threads(), None);
+    }
+
+    #[test]
+    fn camera_builds_thread_pool_once() {
+        let mut c = Camera::new(160, 120, PI / 2.0);
+
+        c.set_threads(Some(3));
+        let pool = c.pool.clone().unwrap();
+
+        assert_eq!(c.threads(), Some(3));
+        assert_eq!(pool.current_num_threads(), 3);
+
+        c.render(&World::default_scene());
+
+        assert!(Arc::ptr_eq(&pool, c.pool.as_ref().unwrap()));
+
+        c.set_threads(Some(1));
+
+        assert!(c.pool.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "camera needs at least one render thread")]
+    fn rendering_without_threads_panics() {
+        Camera::new(160, 120, PI / 2.0).set_threads(Some(0));
+    }
+
+    #[test]
+    fn parallel_render_matches_serial_render_exactly() {
+        let mut w = World::default_scene();
+        let mut light = AreaLight::new(
+            Point::new(5.0, 5.0, -5.0),
+            Vector::new(2.0, 0.0, 0.0),
+            3,
+            Vector::new(0.0, 2.0, 0.0),
+            3,
+            Color::new(0.5, 0.5, 0.5),
+        );
+        light.jitter = true;
+        w.add_light(light);
+        let mut c = Camera::new(40, 30, PI / 2.0);
+        c.set_transform(Transforms::view_transform(
+            Point::new(0.0, 0.0, -5.0),
+            Point::new(0.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+        ));
+
+        c.set_threads(Some(1));
+        let serial = c.render(&w);
+
+        for threads in [None, Some(2), Some(7)] {
+            c.set_threads(threads);
+            let parallel = c.render(&w);
+
+            // compared bit for bit, not within the usual float margin
+            for (a, b) in serial.pixels().iter().zip(parallel.pixels()) {
+                assert_eq!(a.red.to_bits(), b.red.to_bits());
+                assert_eq!(a.green.to_bits(), b.green.to_bits());
+                assert_eq!(a.blue.to_bits(), b.blue.to_bits());
+            }
+        }
+    }
+}
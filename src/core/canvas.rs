@@ -1,11 +1,20 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    ops::{Index, IndexMut},
+    slice::{ChunksExact, ChunksExactMut, ChunksMut},
+};
 
 use crate::core::color::Color;
 
+/// A grid of colors, `width` pixels across and `height` pixels down, with
+/// `(0, 0)` at the top left.
+///
+/// Pixels are stored in one contiguous buffer, row after row, so they can
+/// be handed out a few rows at a time to parallel writers.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Canvas {
-    pub width: usize,
-    pub height: usize,
-    pub pixels: Vec<Vec<Color>>,
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
 }
 
 impl Canvas {
@@ -17,30 +26,121 @@ impl Canvas {
         Canvas {
             width,
             height,
-            pixels: vec![vec![color; height]; width],
+            pixels: vec![color; width * height],
         }
     }
 
-    pub fn set_pixel(&mut self, x: usize, y: usize, color: Color) -> () {
+    /// A canvas over `pixels`, given row by row from the top. Returns `None`
+    /// if there aren't exactly `width * height` of them.
+    pub fn from_pixels(width: usize, height: usize, pixels: Vec<Color>) -> Option<Canvas> {
+        if pixels.len() != width * height {
+            return None;
+        }
+
+        Some(Canvas {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every pixel, row by row from the top.
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    pub fn pixels_mut(&mut self) -> &mut [Color] {
+        &mut self.pixels
+    }
+
+    pub fn into_pixels(self) -> Vec<Color> {
+        self.pixels
+    }
+
+    /// The pixels in the old `[x][y]` layout, one vector per column.
+    #[deprecated(note = "index with `canvas[[x, y]]` or iterate `rows()` instead")]
+    pub fn columns(&self) -> Vec<Vec<Color>> {
+        (0..self.width)
+            .map(|x| (0..self.height).map(|y| self[[x, y]]).collect())
+            .collect()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&Color> {
+        self.offset(x, y).map(|i| &self.pixels[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Color> {
+        self.offset(x, y).map(move |i| &mut self.pixels[i])
+    }
+
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: Color) {
         self[[x, y]] = color;
     }
+
+    /// The rows of the canvas, from the top.
+    pub fn rows(&self) -> ChunksExact<'_, Color> {
+        self.pixels.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, Color> {
+        self.pixels.chunks_exact_mut(self.width.max(1))
+    }
+
+    /// Splits the canvas into bands of `rows` whole rows, the last one
+    /// possibly shorter, which can be written to independently.
+    pub fn chunks_mut(&mut self, rows: usize) -> ChunksMut<'_, Color> {
+        self.pixels.chunks_mut((self.width * rows).max(1))
+    }
+
+    /// Every pixel along with its `x` and `y`, row by row from the top.
+    pub fn enumerate_pixels(&self) -> impl Iterator<Item = (usize, usize, &Color)> {
+        let width = self.width;
+
+        self.pixels
+            .iter()
+            .enumerate()
+            .map(move |(i, color)| (i % width, i / width, color))
+    }
+
+    fn offset(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
 }
 
 impl Index<[usize; 2]> for Canvas {
     type Output = Color;
 
     fn index(&self, index: [usize; 2]) -> &Self::Output {
-        let [i, j] = index;
+        let [x, y] = index;
 
-        &self.pixels[i][j]
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "pixel ({x}, {y}) out of bounds for {}x{} canvas",
+                self.width, self.height
+            )
+        })
     }
 }
 
 impl IndexMut<[usize; 2]> for Canvas {
     fn index_mut(&mut self, index: [usize; 2]) -> &mut Color {
-        let [i, j] = index;
+        let [x, y] = index;
+        let (width, height) = (self.width, self.height);
 
-        &mut self.pixels[i][j]
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("pixel ({x}, {y}) out of bounds for {width}x{height} canvas"))
     }
 }
 
@@ -52,11 +152,20 @@ mod canvas_tests {
     fn create_canvas() {
         let canvas = Canvas::new(10, 10);
 
-        for pixel in canvas.pixels.iter().flatten() {
+        for pixel in canvas.pixels() {
             assert_eq!(*pixel, Color::BLACK);
         }
     }
 
+    #[test]
+    fn canvas_size() {
+        let canvas = Canvas::new(10, 20);
+
+        assert_eq!(canvas.width(), 10);
+        assert_eq!(canvas.height(), 20);
+        assert_eq!(canvas.pixels().len(), 200);
+    }
+
     #[test]
     fn create_canvas_filled() {
         let color = Color {
@@ -66,7 +175,7 @@ mod canvas_tests {
         };
         let canvas = Canvas::new_filled(10, 10, color);
 
-        for pixel in canvas.pixels.iter().flatten() {
+        for pixel in canvas.pixels() {
             assert_eq!(*pixel, color);
         }
     }
@@ -79,4 +188,123 @@ mod canvas_tests {
 
         assert_eq!(c[[5, 5]], Color::WHITE);
     }
+
+    #[test]
+    fn pixels_are_stored_row_by_row() {
+        let mut c = Canvas::new(3, 2);
+
+        c[[2, 0]] = Color::WHITE;
+        c[[0, 1]] = Color::new(1.0, 0.0, 0.0);
+
+        assert_eq!(c.pixels()[2], Color::WHITE);
+        assert_eq!(c.pixels()[3], Color::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn creating_canvas_from_pixels() {
+        let pixels = vec![Color::BLACK, Color::WHITE, Color::WHITE, Color::BLACK];
+
+        let c = Canvas::from_pixels(2, 2, pixels.clone()).unwrap();
+
+        assert_eq!(c[[1, 0]], Color::WHITE);
+        assert_eq!(c[[0, 1]], Color::WHITE);
+        assert_eq!(c.into_pixels(), pixels);
+        assert!(Canvas::from_pixels(3, 2, vec![Color::BLACK; 5]).is_none());
+    }
+
+    #[test]
+    fn checked_access_outside_canvas() {
+        let mut c = Canvas::new(4, 3);
+
+        assert_eq!(c.get(3, 2), Some(&Color::BLACK));
+        assert_eq!(c.get(4, 0), None);
+        assert_eq!(c.get(0, 3), None);
+
+        *c.get_mut(1, 2).unwrap() = Color::WHITE;
+        assert_eq!(c[[1, 2]], Color::WHITE);
+        assert!(c.get_mut(0, 3).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "pixel (4, 0) out of bounds for 4x3 canvas")]
+    fn indexing_outside_canvas_panics() {
+        let c = Canvas::new(4, 3);
+
+        let _ = c[[4, 0]];
+    }
+
+    #[test]
+    fn iterating_rows() {
+        let mut c = Canvas::new(3, 2);
+
+        for (y, row) in c.rows_mut().enumerate() {
+            row[y] = Color::WHITE;
+        }
+
+        let rows: Vec<&[Color]> = c.rows().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], [Color::WHITE, Color::BLACK, Color::BLACK]);
+        assert_eq!(rows[1], [Color::BLACK, Color::WHITE, Color::BLACK]);
+    }
+
+    #[test]
+    fn enumerating_pixels() {
+        let mut c = Canvas::new(2, 2);
+        c[[1, 0]] = Color::WHITE;
+
+        let pixels: Vec<(usize, usize, Color)> = c
+            .enumerate_pixels()
+            .map(|(x, y, color)| (x, y, *color))
+            .collect();
+
+        assert_eq!(
+            pixels,
+            vec![
+                (0, 0, Color::BLACK),
+                (1, 0, Color::WHITE),
+                (0, 1, Color::BLACK),
+                (1, 1, Color::BLACK),
+            ]
+        );
+    }
+
+    #[test]
+    fn splitting_canvas_into_bands_of_rows() {
+        let mut c = Canvas::new(4, 5);
+
+        let lengths: Vec<usize> = c.chunks_mut(2).map(|band| band.len()).collect();
+
+        assert_eq!(lengths, vec![8, 8, 4]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn columns_keep_old_layout() {
+        let mut c = Canvas::new(3, 2);
+        c[[2, 1]] = Color::WHITE;
+
+        let columns = c.columns();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[2], vec![Color::BLACK, Color::WHITE]);
+    }
+
+    #[test]
+    fn canvas_rows_can_be_written_from_several_threads() {
+        let mut c = Canvas::new(4, 3);
+
+        std::thread::scope(|scope| {
+            for (y, row) in c.rows_mut().enumerate() {
+                scope.spawn(move || {
+                    for (x, pixel) in row.iter_mut().enumerate() {
+                        *pixel = Color::new(x as f64, y as f64, 0.0);
+                    }
+                });
+            }
+        });
+
+        for (x, y, pixel) in c.enumerate_pixels() {
+            assert_eq!(*pixel, Color::new(x as f64, y as f64, 0.0));
+        }
+    }
 }
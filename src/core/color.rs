@@ -1,6 +1,8 @@
-use float_cmp::approx_eq;
+use float_cmp::ApproxEq;
 use std::ops::{Add, Div, Mul, Sub};
 
+use crate::utils::utils::FLOAT_MARGIN;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Color {
     pub red: f64,
@@ -27,20 +29,39 @@ impl Color {
         blue: 0.0,
     };
 
+    pub const fn new(red: f64, green: f64, blue: f64) -> Self {
+        Color { red, green, blue }
+    }
+
+    /// The color as 8 bit samples, rounded to the nearest value and clamped
+    /// to 0 to 255. Every 8 bit image the canvas is written to, PPM or
+    /// otherwise, goes through this.
     pub fn to_rgb(&self) -> image::Rgb<u8> {
+        let quantize = |c: f64| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+
         image::Rgb([
-            (self.red * 255.0).clamp(0.0, 255.0) as u8,
-            (self.green * 255.0).clamp(0.0, 255.0) as u8,
-            (self.blue * 255.0).clamp(0.0, 255.0) as u8,
+            quantize(self.red),
+            quantize(self.green),
+            quantize(self.blue),
         ])
     }
+
+    pub fn from_rgb(rgb: image::Rgb<u8>) -> Self {
+        let [red, green, blue] = rgb.0;
+
+        Color::new(
+            red as f64 / 255.0,
+            green as f64 / 255.0,
+            blue as f64 / 255.0,
+        )
+    }
 }
 
 impl PartialEq for Color {
     fn eq(&self, other: &Self) -> bool {
-        approx_eq!(f64, self.red, other.red)
-            && approx_eq!(f64, self.green, other.green)
-            && approx_eq!(f64, self.blue, other.blue)
+        self.red.approx_eq(other.red, FLOAT_MARGIN)
+            && self.green.approx_eq(other.green, FLOAT_MARGIN)
+            && self.blue.approx_eq(other.blue, FLOAT_MARGIN)
     }
 }
 
@@ -191,6 +212,13 @@ mod color_tests {
             blue: 0.5,
         };
 
-        assert_eq!(c.to_rgb(), image::Rgb([255, 255, 127]));
+        assert_eq!(c.to_rgb(), image::Rgb([255, 255, 128]));
+    }
+
+    #[test]
+    fn from_rgb() {
+        let c = Color::from_rgb(image::Rgb([255, 51, 0]));
+
+        assert_eq!(c, Color::new(1.0, 0.2, 0.0));
     }
 }
@@ -21,6 +21,12 @@ impl Matrix2 {
     }
 }
 
+impl Default for Matrix2 {
+    fn default() -> Self {
+        Matrix2::new()
+    }
+}
+
 impl Index<[usize; 2]> for Matrix2 {
     type Output = f64;
 
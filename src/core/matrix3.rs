@@ -65,6 +65,12 @@ impl Matrix3 {
     }
 }
 
+impl Default for Matrix3 {
+    fn default() -> Self {
+        Matrix3::new()
+    }
+}
+
 impl Index<[usize; 2]> for Matrix3 {
     type Output = f64;
 
@@ -80,11 +80,7 @@ impl Matrix4 {
     }
 
     pub fn is_invertible(&self) -> bool {
-        if approx_eq!(f64, self.determinant(), 0.0) {
-            false
-        } else {
-            true
-        }
+        !approx_eq!(f64, self.determinant(), 0.0)
     }
 
     pub fn inverse(&self) -> Matrix4 {
@@ -105,7 +101,7 @@ impl Matrix4 {
         m
     }
 
-    const IDENTITY: Matrix4 = Matrix4::from([
+    pub const IDENTITY: Matrix4 = Matrix4::from([
         [1.0, 0.0, 0.0, 0.0],
         [0.0, 1.0, 0.0, 0.0],
         [0.0, 0.0, 1.0, 0.0],
@@ -113,6 +109,12 @@ impl Matrix4 {
     ]);
 }
 
+impl Default for Matrix4 {
+    fn default() -> Self {
+        Matrix4::new()
+    }
+}
+
 impl Index<[usize; 2]> for Matrix4 {
     type Output = f64;
 
@@ -439,7 +441,7 @@ pub mod matrix_tests {
         ]);
 
         assert_approx_eq!(f64, m.determinant(), -2120.0, epsilon = EPSILON);
-        assert_eq!(m.is_invertible(), true);
+        assert!(m.is_invertible());
 
         let m = Matrix4::from([
             [-4.0, 2.0, -2.0, -3.0],
@@ -449,7 +451,7 @@ pub mod matrix_tests {
         ]);
 
         assert_approx_eq!(f64, m.determinant(), 0.0, epsilon = EPSILON);
-        assert_eq!(m.is_invertible(), false);
+        assert!(!m.is_invertible());
     }
 
     #[test]
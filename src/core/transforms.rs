@@ -1,4 +1,4 @@
-use super::matrix4::Matrix4;
+use super::{matrix4::Matrix4, point::Point, vector::Vector};
 
 pub struct Transforms {}
 
@@ -56,14 +56,28 @@ impl Transforms {
             [0.0, 0.0, 0.0, 1.0],
         ])
     }
+
+    /// Orients the world relative to an eye at `from` looking towards `to`.
+    pub fn view_transform(from: Point, to: Point, up: Vector) -> Matrix4 {
+        let forward = (to - from).normalize();
+        let left = forward.cross(&up.normalize());
+        let true_up = left.cross(&forward);
+
+        let orientation = Matrix4::from([
+            [left.x, left.y, left.z, 0.0],
+            [true_up.x, true_up.y, true_up.z, 0.0],
+            [-forward.x, -forward.y, -forward.z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        orientation * Transforms::translation(-from.x, -from.y, -from.z)
+    }
 }
 
 #[cfg(test)]
 pub mod transforms_tests {
     use std::f64::consts::PI;
 
-    use crate::core::{point::Point, vector::Vector};
-
     use super::*;
 
     #[test]
@@ -266,4 +280,54 @@ pub mod transforms_tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn view_transform_for_default_orientation() {
+        let from = Point::new(0.0, 0.0, 0.0);
+        let to = Point::new(0.0, 0.0, -1.0);
+        let up = Vector::new(0.0, 1.0, 0.0);
+
+        let actual = Transforms::view_transform(from, to, up);
+
+        assert_eq!(Matrix4::IDENTITY, actual);
+    }
+
+    #[test]
+    fn view_transform_looking_in_positive_z_direction() {
+        let from = Point::new(0.0, 0.0, 0.0);
+        let to = Point::new(0.0, 0.0, 1.0);
+        let up = Vector::new(0.0, 1.0, 0.0);
+
+        let actual = Transforms::view_transform(from, to, up);
+
+        assert_eq!(Transforms::scaling(-1.0, 1.0, -1.0), actual);
+    }
+
+    #[test]
+    fn view_transform_moves_the_world() {
+        let from = Point::new(0.0, 0.0, 8.0);
+        let to = Point::new(0.0, 0.0, 0.0);
+        let up = Vector::new(0.0, 1.0, 0.0);
+
+        let actual = Transforms::view_transform(from, to, up);
+
+        assert_eq!(Transforms::translation(0.0, 0.0, -8.0), actual);
+    }
+
+    #[test]
+    fn arbitrary_view_transform() {
+        let from = Point::new(1.0, 3.0, 2.0);
+        let to = Point::new(4.0, -2.0, 8.0);
+        let up = Vector::new(1.0, 1.0, 0.0);
+
+        let actual = Transforms::view_transform(from, to, up);
+        let expected = Matrix4::from([
+            [-0.50709, 0.50709, 0.67612, -2.36643],
+            [0.76772, 0.60609, 0.12122, -2.82843],
+            [-0.35857, 0.59761, -0.71714, 0.00000],
+            [0.00000, 0.00000, 0.00000, 1.00000],
+        ]);
+
+        assert_eq!(expected, actual);
+    }
 }
@@ -42,6 +42,11 @@ impl Vector {
             w: 0.0,
         }
     }
+
+    /// Reflects this vector around `normal`.
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * 2.0 * (*self * *normal)
+    }
 }
 
 impl PartialEq for Vector {
@@ -247,4 +252,24 @@ mod point_tests {
         assert_eq!(v1.cross(&v2), expected);
         assert_eq!(v2.cross(&v1), -expected);
     }
+
+    #[test]
+    fn reflecting_vector_approaching_at_45_degrees() {
+        let v = Vector::new(1.0, -1.0, 0.0);
+        let n = Vector::new(0.0, 1.0, 0.0);
+
+        let expected = Vector::new(1.0, 1.0, 0.0);
+
+        assert_eq!(v.reflect(&n), expected);
+    }
+
+    #[test]
+    fn reflecting_vector_off_slanted_surface() {
+        let v = Vector::new(0.0, -1.0, 0.0);
+        let n = Vector::new(2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0, 0.0);
+
+        let expected = Vector::new(1.0, 0.0, 0.0);
+
+        assert_eq!(v.reflect(&n), expected);
+    }
 }
@@ -0,0 +1,448 @@
+use std::ops::Index;
+
+use float_cmp::ApproxEq;
+
+use crate::{
+    core::{point::Point, vector::Vector},
+    ray::Ray,
+    shapes::shape::{same_shape, Shape},
+    utils::utils::{EPSILON, FLOAT_MARGIN},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Intersection<'a> {
+    pub t: f64,
+    pub object: &'a dyn Shape,
+    /// Barycentric coordinates of the hit, only set by triangles.
+    pub u: f64,
+    pub v: f64,
+}
+
+impl<'a> Intersection<'a> {
+    pub fn new(t: f64, object: &'a dyn Shape) -> Self {
+        Intersection::with_uv(t, object, 0.0, 0.0)
+    }
+
+    pub fn with_uv(t: f64, object: &'a dyn Shape, u: f64, v: f64) -> Self {
+        Intersection { t, object, u, v }
+    }
+
+    /// Precomputes the values needed to shade this intersection of `ray`.
+    ///
+    /// `xs` are all the intersections along the ray, this one included,
+    /// which tell the materials the ray is passing between.
+    pub fn prepare_computations(&self, ray: &Ray, xs: &Intersections<'a>) -> Computations<'a> {
+        let point = ray.at(self.t);
+        let eyev = -ray.direction;
+        let mut normalv = self.object.normal_at(point, self);
+
+        let inside = normalv * eyev < 0.0;
+
+        if inside {
+            normalv = -normalv;
+        }
+
+        let (n1, n2) = self.refractive_indices(xs);
+
+        Computations {
+            t: self.t,
+            object: self.object,
+            point,
+            eyev,
+            normalv,
+            inside,
+            over_point: point + normalv * EPSILON,
+            under_point: point - normalv * EPSILON,
+            reflectv: ray.direction.reflect(&normalv),
+            n1,
+            n2,
+        }
+    }
+
+    /// Refractive indices of the materials on either side of this
+    /// intersection, found by tracking which objects the ray is inside of.
+    fn refractive_indices(&self, xs: &Intersections<'a>) -> (f64, f64) {
+        let mut containers: Vec<&dyn Shape> = vec![];
+        let index_of = |containers: &[&dyn Shape]| {
+            containers
+                .last()
+                .map_or(1.0, |object| object.material().refractive_index)
+        };
+
+        for i in xs.iter() {
+            let is_hit = i == self;
+            let n1 = index_of(&containers);
+
+            match containers.iter().position(|&o| same_shape(o, i.object)) {
+                Some(position) => {
+                    containers.remove(position);
+                }
+                None => containers.push(i.object),
+            }
+
+            if is_hit {
+                return (n1, index_of(&containers));
+            }
+        }
+
+        (1.0, 1.0)
+    }
+}
+
+impl PartialEq for Intersection<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.t.approx_eq(other.t, FLOAT_MARGIN) && same_shape(self.object, other.object)
+    }
+}
+
+/// Values derived from an intersection that shading needs.
+#[derive(Debug, Clone, Copy)]
+pub struct Computations<'a> {
+    pub t: f64,
+    pub object: &'a dyn Shape,
+    pub point: Point,
+    pub eyev: Vector,
+    pub normalv: Vector,
+    pub inside: bool,
+    /// `point` nudged slightly along the normal, so rays cast from it don't
+    /// hit the surface they start on.
+    pub over_point: Point,
+    /// `point` nudged slightly below the surface, where refracted rays
+    /// start.
+    pub under_point: Point,
+    /// Direction the ray bounces off to when the surface is reflective.
+    pub reflectv: Vector,
+    /// Refractive index of the material the ray is leaving.
+    pub n1: f64,
+    /// Refractive index of the material the ray is entering.
+    pub n2: f64,
+}
+
+impl Computations<'_> {
+    /// Schlick's approximation of the Fresnel effect: the fraction of light
+    /// reflected rather than refracted at this hit.
+    pub fn schlick(&self) -> f64 {
+        let mut cos = self.eyev * self.normalv;
+
+        if self.n1 > self.n2 {
+            let n = self.n1 / self.n2;
+            let sin2_t = n.powi(2) * (1.0 - cos.powi(2));
+
+            // total internal reflection
+            if sin2_t > 1.0 {
+                return 1.0;
+            }
+
+            cos = (1.0 - sin2_t).sqrt();
+        }
+
+        let r0 = ((self.n1 - self.n2) / (self.n1 + self.n2)).powi(2);
+
+        r0 + (1.0 - r0) * (1.0 - cos).powi(5)
+    }
+}
+
+/// A collection of intersections, always kept sorted by increasing `t`.
+#[derive(Debug, Clone, Default)]
+pub struct Intersections<'a> {
+    data: Vec<Intersection<'a>>,
+}
+
+impl<'a> Intersections<'a> {
+    pub fn new(mut data: Vec<Intersection<'a>>) -> Self {
+        data.sort_by(|a, b| a.t.total_cmp(&b.t));
+
+        Intersections { data }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Intersection<'a>> {
+        self.data.iter()
+    }
+
+    /// The visible intersection: the one with the lowest non-negative `t`.
+    pub fn hit(&self) -> Option<&Intersection<'a>> {
+        self.data.iter().find(|i| i.t >= 0.0)
+    }
+}
+
+impl<'a> Index<usize> for Intersections<'a> {
+    type Output = Intersection<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<'a> IntoIterator for Intersections<'a> {
+    type Item = Intersection<'a>;
+    type IntoIter = std::vec::IntoIter<Intersection<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod intersection_tests {
+    use std::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+    use float_cmp::assert_approx_eq;
+
+    use crate::{
+        core::transforms::Transforms,
+        shapes::{
+            plane::Plane,
+            sphere::{sphere_tests::glass_sphere, Sphere},
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn intersection_encapsulates_t_and_object() {
+        let s = Sphere::new();
+
+        let i = Intersection::new(3.5, &s);
+
+        assert_eq!(i.t, 3.5);
+        assert!(same_shape(i.object, &s));
+    }
+
+    #[test]
+    fn intersections_are_sorted() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(5.0, &s);
+        let i2 = Intersection::new(-3.0, &s);
+        let i3 = Intersection::new(2.0, &s);
+
+        let xs = Intersections::new(vec![i1, i2, i3]);
+
+        assert_eq!(xs.len(), 3);
+        assert_eq!(xs[0].t, -3.0);
+        assert_eq!(xs[1].t, 2.0);
+        assert_eq!(xs[2].t, 5.0);
+    }
+
+    #[test]
+    fn hit_when_all_intersections_have_positive_t() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(1.0, &s);
+        let i2 = Intersection::new(2.0, &s);
+
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), Some(&i1));
+    }
+
+    #[test]
+    fn hit_when_some_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(-1.0, &s);
+        let i2 = Intersection::new(1.0, &s);
+
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), Some(&i2));
+    }
+
+    #[test]
+    fn hit_when_all_intersections_have_negative_t() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(-2.0, &s);
+        let i2 = Intersection::new(-1.0, &s);
+
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), None);
+    }
+
+    #[test]
+    fn hit_is_always_lowest_non_negative_intersection() {
+        let s = Sphere::new();
+        let i1 = Intersection::new(5.0, &s);
+        let i2 = Intersection::new(7.0, &s);
+        let i3 = Intersection::new(-3.0, &s);
+        let i4 = Intersection::new(2.0, &s);
+
+        let xs = Intersections::new(vec![i1, i2, i3, i4]);
+
+        assert_eq!(xs.hit(), Some(&i4));
+    }
+
+    #[test]
+    fn precomputing_state_of_intersection() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = Sphere::new();
+        let i = Intersection::new(4.0, &shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert_eq!(comps.t, i.t);
+        assert!(same_shape(comps.object, &shape));
+        assert_eq!(comps.point, Point::new(0.0, 0.0, -1.0));
+        assert_eq!(comps.eyev, Vector::new(0.0, 0.0, -1.0));
+        assert_eq!(comps.normalv, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn hit_when_intersection_occurs_on_outside() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = Sphere::new();
+        let i = Intersection::new(4.0, &shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert!(!comps.inside);
+    }
+
+    #[test]
+    fn hit_when_intersection_occurs_on_inside() {
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = Sphere::new();
+        let i = Intersection::new(1.0, &shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert_eq!(comps.point, Point::new(0.0, 0.0, 1.0));
+        assert_eq!(comps.eyev, Vector::new(0.0, 0.0, -1.0));
+        assert!(comps.inside);
+        // normal would have been (0, 0, 1), but is inverted
+        assert_eq!(comps.normalv, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn hit_should_offset_point() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut shape = Sphere::new();
+        shape.set_transform(Transforms::translation(0.0, 0.0, 1.0));
+        let i = Intersection::new(5.0, &shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert!(comps.over_point.z < -EPSILON / 2.0);
+        assert!(comps.point.z > comps.over_point.z);
+    }
+
+    #[test]
+    fn precomputing_reflection_vector() {
+        let shape = Plane::new();
+        let r = Ray::from(
+            Point::new(0.0, 1.0, -1.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let i = Intersection::new(SQRT_2, &shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert_eq!(
+            comps.reflectv,
+            Vector::new(0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2)
+        );
+    }
+
+    #[test]
+    fn finding_n1_and_n2_at_various_intersections() {
+        let mut a = glass_sphere();
+        a.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+        a.props_mut().material_mut().refractive_index = 1.5;
+        let mut b = glass_sphere();
+        b.set_transform(Transforms::translation(0.0, 0.0, -0.25));
+        b.props_mut().material_mut().refractive_index = 2.0;
+        let mut c = glass_sphere();
+        c.set_transform(Transforms::translation(0.0, 0.0, 0.25));
+        c.props_mut().material_mut().refractive_index = 2.5;
+        let r = Ray::from(Point::new(0.0, 0.0, -4.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(2.0, &a),
+            Intersection::new(2.75, &b),
+            Intersection::new(3.25, &c),
+            Intersection::new(4.75, &b),
+            Intersection::new(5.25, &c),
+            Intersection::new(6.0, &a),
+        ]);
+        let expected = [
+            (1.0, 1.5),
+            (1.5, 2.0),
+            (2.0, 2.5),
+            (2.5, 2.5),
+            (2.5, 1.5),
+            (1.5, 1.0),
+        ];
+
+        for (i, (n1, n2)) in xs.iter().zip(expected) {
+            let comps = i.prepare_computations(&r, &xs);
+
+            assert_eq!(comps.n1, n1);
+            assert_eq!(comps.n2, n2);
+        }
+    }
+
+    #[test]
+    fn under_point_is_offset_below_surface() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut shape = glass_sphere();
+        shape.set_transform(Transforms::translation(0.0, 0.0, 1.0));
+        let i = Intersection::new(5.0, &shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert!(comps.under_point.z > EPSILON / 2.0);
+        assert!(comps.point.z < comps.under_point.z);
+    }
+
+    #[test]
+    fn schlick_approximation_under_total_internal_reflection() {
+        let shape = glass_sphere();
+        let r = Ray::from(
+            Point::new(0.0, 0.0, FRAC_1_SQRT_2),
+            Vector::new(0.0, 1.0, 0.0),
+        );
+        let xs = Intersections::new(vec![
+            Intersection::new(-FRAC_1_SQRT_2, &shape),
+            Intersection::new(FRAC_1_SQRT_2, &shape),
+        ]);
+
+        let comps = xs[1].prepare_computations(&r, &xs);
+
+        assert_eq!(comps.schlick(), 1.0);
+    }
+
+    #[test]
+    fn schlick_approximation_with_perpendicular_viewing_angle() {
+        let shape = glass_sphere();
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(-1.0, &shape),
+            Intersection::new(1.0, &shape),
+        ]);
+
+        let comps = xs[1].prepare_computations(&r, &xs);
+
+        assert_approx_eq!(f64, comps.schlick(), 0.04, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn schlick_approximation_with_small_angle_and_n2_greater_than_n1() {
+        let shape = glass_sphere();
+        let r = Ray::from(Point::new(0.0, 0.99, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![Intersection::new(1.8589, &shape)]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+
+        assert_approx_eq!(f64, comps.schlick(), 0.48873, epsilon = EPSILON);
+    }
+}
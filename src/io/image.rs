@@ -1,21 +1,164 @@
-use crate::core::canvas::Canvas;
+use std::{fs, io::Cursor, path::Path};
+
+use exr::prelude::{SpecificChannels, Vec2, WritableImage};
+use half::f16;
+use image::{
+    codecs::{hdr::HdrEncoder, openexr::OpenExrEncoder},
+    error::{EncodingError, ImageFormatHint, ParameterError, ParameterErrorKind},
+    ColorType, ImageEncoder, ImageError, ImageFormat, ImageResult,
+};
+
+use crate::core::{canvas::Canvas, color::Color};
 
 type Image = image::ImageBuffer<image::Rgb<u8>, Vec<u8>>;
 
 pub fn make_image(canvas: &Canvas) -> Image {
-    let mut buffer = image::ImageBuffer::new(canvas.width as u32, canvas.height as u32);
+    let mut buffer = image::ImageBuffer::new(canvas.width() as u32, canvas.height() as u32);
 
-    for (x, y, pixel) in buffer.enumerate_pixels_mut() {
-        let color = canvas[[x as usize, y as usize]];
+    for (pixel, color) in buffer.pixels_mut().zip(canvas.pixels()) {
         *pixel = color.to_rgb();
     }
 
     buffer
 }
 
+/// Converts any decoded image to a canvas, dropping the alpha channel.
+pub fn canvas_from_image(image: &image::DynamicImage) -> Canvas {
+    let rgb = image.to_rgb8();
+    let pixels = rgb.pixels().map(|pixel| Color::from_rgb(*pixel)).collect();
+
+    Canvas::from_pixels(rgb.width() as usize, rgb.height() as usize, pixels)
+        .expect("decoded image has one pixel per position")
+}
+
+/// Reads an image file in any format the `image` crate can decode, such as
+/// PNG, JPEG or PPM, into a canvas.
+pub fn load_image(path: impl AsRef<Path>) -> image::ImageResult<Canvas> {
+    Ok(canvas_from_image(&image::open(path)?))
+}
+
+/// Encodes the canvas as a Radiance `.hdr` file, keeping colors brighter
+/// than white.
+///
+/// Each pixel is stored as three 8 bit mantissas sharing an exponent, so
+/// colors keep about 1% precision across a huge range. Negative values
+/// become 0 and anything brighter than the format can hold, infinity
+/// included, is clamped to its brightest color.
+pub fn encode_hdr(canvas: &Canvas) -> ImageResult<Vec<u8>> {
+    check_not_empty(canvas)?;
+
+    let pixels: Vec<image::Rgb<f32>> = canvas
+        .pixels()
+        .iter()
+        .map(|color| image::Rgb([color.red, color.green, color.blue].map(hdr_sample)))
+        .collect();
+
+    let mut hdr = Vec::new();
+    HdrEncoder::new(&mut hdr).encode(&pixels, canvas.width(), canvas.height())?;
+
+    Ok(hdr)
+}
+
+pub fn write_hdr(canvas: &Canvas, path: impl AsRef<Path>) -> ImageResult<()> {
+    Ok(fs::write(path, encode_hdr(canvas)?)?)
+}
+
+/// A sample the encoder can store. Radiance exponents stop at 2^127, and
+/// `NaN` is treated as black.
+fn hdr_sample(sample: f64) -> f32 {
+    if sample.is_nan() {
+        return 0.0;
+    }
+
+    (sample as f32).clamp(0.0, 1e38)
+}
+
+/// Precision of the samples in an OpenEXR file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExrPixelType {
+    /// 16 bit floats, half the size and plenty for finished images.
+    Half,
+    /// 32 bit floats.
+    Float,
+}
+
+/// Encodes the canvas as an OpenEXR file, with linear red, green and blue
+/// channels holding the colors as they are.
+pub fn encode_exr(canvas: &Canvas, pixel_type: ExrPixelType) -> ImageResult<Vec<u8>> {
+    check_not_empty(canvas)?;
+
+    let mut exr = Cursor::new(Vec::new());
+
+    match pixel_type {
+        // the image crate only writes 32 bit samples, so halves go through
+        // the exr crate it is built on
+        ExrPixelType::Half => exr::prelude::Image::from_channels(
+            (canvas.width(), canvas.height()),
+            SpecificChannels::rgb(|position: Vec2<usize>| {
+                let color = canvas[[position.x(), position.y()]];
+
+                (
+                    f16::from_f64(color.red),
+                    f16::from_f64(color.green),
+                    f16::from_f64(color.blue),
+                )
+            }),
+        )
+        .write()
+        .to_buffered(&mut exr)
+        .map_err(|error| {
+            ImageError::Encoding(EncodingError::new(
+                ImageFormatHint::Exact(ImageFormat::OpenExr),
+                error,
+            ))
+        })?,
+        ExrPixelType::Float => {
+            let samples: Vec<u8> = canvas
+                .pixels()
+                .iter()
+                .flat_map(|color| [color.red, color.green, color.blue])
+                .flat_map(|sample| (sample as f32).to_ne_bytes())
+                .collect();
+
+            OpenExrEncoder::new(&mut exr).write_image(
+                &samples,
+                canvas.width() as u32,
+                canvas.height() as u32,
+                ColorType::Rgb32F,
+            )?
+        }
+    }
+
+    Ok(exr.into_inner())
+}
+
+pub fn write_exr(
+    canvas: &Canvas,
+    path: impl AsRef<Path>,
+    pixel_type: ExrPixelType,
+) -> ImageResult<()> {
+    Ok(fs::write(path, encode_exr(canvas, pixel_type)?)?)
+}
+
+/// Fails for canvases without pixels, which can't be written to a file or
+/// sampled as a texture.
+pub(crate) fn check_not_empty(canvas: &Canvas) -> ImageResult<()> {
+    if canvas.width() == 0 || canvas.height() == 0 {
+        return Err(ImageError::Parameter(ParameterError::from_kind(
+            ParameterErrorKind::DimensionMismatch,
+        )));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod image_tests {
-    use crate::core::color::Color;
+    use std::io::BufReader;
+
+    use image::codecs::hdr::HdrDecoder;
+
+    use crate::utils::utils::temp_path;
 
     use super::*;
 
@@ -35,4 +178,156 @@ mod image_tests {
 
         assert_eq!(*image.get_pixel(5, 5), image::Rgb([255, 255, 255]));
     }
+
+    #[test]
+    fn image_to_canvas() {
+        let mut c = Canvas::new(3, 2);
+        c[[0, 0]] = Color::new(1.0, 0.2, 0.0);
+        c[[2, 1]] = Color::WHITE;
+        let image = image::DynamicImage::ImageRgb8(make_image(&c));
+
+        let actual = canvas_from_image(&image);
+
+        assert_eq!(actual, c);
+    }
+
+    #[test]
+    fn loading_png_and_ppm_files() {
+        let mut c = Canvas::new(4, 3);
+        c[[1, 2]] = Color::new(0.2, 0.4, 0.6);
+        for name in ["loading_image.png", "loading_image.ppm"] {
+            let path = temp_path(name);
+            make_image(&c).save(&path).unwrap();
+
+            let actual = load_image(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            assert_eq!(actual, c);
+        }
+    }
+
+    #[test]
+    fn loading_missing_file_fails() {
+        assert!(load_image("does/not/exist.png").is_err());
+    }
+
+    fn decode_hdr(hdr: &[u8]) -> (usize, usize, Vec<Color>) {
+        let decoder = HdrDecoder::new(BufReader::new(hdr)).unwrap();
+        let metadata = decoder.metadata();
+        let pixels = decoder
+            .read_image_hdr()
+            .unwrap()
+            .into_iter()
+            .map(|image::Rgb([r, g, b])| Color::new(r as f64, g as f64, b as f64))
+            .collect();
+
+        (metadata.width as usize, metadata.height as usize, pixels)
+    }
+
+    fn decode_exr(exr: &[u8]) -> image::Rgb32FImage {
+        image::load_from_memory_with_format(exr, ImageFormat::OpenExr)
+            .unwrap()
+            .to_rgb32f()
+    }
+
+    #[test]
+    fn hdr_keeps_colors_brighter_than_white() {
+        // narrow rows are stored as they are, wider ones run length encoded
+        for width in [2, 20] {
+            let mut c = Canvas::new_filled(width, 3, Color::new(0.2, 0.4, 0.6));
+            c[[1, 0]] = Color::new(5.0, 1.5, 0.75);
+            c[[width - 1, 2]] = Color::new(1000.0, 0.0, 250.0);
+            for x in 0..width {
+                c[[x, 1]] = Color::new(x as f64, 0.0, 1.0);
+            }
+
+            let (w, h, pixels) = decode_hdr(&encode_hdr(&c).unwrap());
+
+            assert_eq!((w, h), (width, 3));
+            for (expected, actual) in c.pixels().iter().zip(pixels) {
+                let tolerance = expected.red.max(expected.green).max(expected.blue) / 128.0;
+
+                assert!((actual.red - expected.red).abs() <= tolerance);
+                assert!((actual.green - expected.green).abs() <= tolerance);
+                assert!((actual.blue - expected.blue).abs() <= tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn hdr_clamps_colors_it_cannot_hold() {
+        let mut c = Canvas::new(3, 1);
+        c[[0, 0]] = Color::new(f64::INFINITY, 1.0, 0.0);
+        c[[1, 0]] = Color::new(-1.0, f64::NAN, 1e300);
+
+        let (_, _, pixels) = decode_hdr(&encode_hdr(&c).unwrap());
+
+        assert!(pixels[0].red > 1e37 && pixels[0].red.is_finite());
+        assert_eq!((pixels[1].red, pixels[1].green), (0.0, 0.0));
+        assert!(pixels[1].blue > 1e37 && pixels[1].blue.is_finite());
+        assert_eq!(pixels[2], Color::BLACK);
+    }
+
+    fn exr_canvas() -> Canvas {
+        let mut c = Canvas::new(3, 2);
+        c[[1, 1]] = Color::new(100.0, 0.25, 4.5);
+
+        c
+    }
+
+    #[test]
+    fn exr_keeps_exact_values() {
+        for pixel_type in [ExrPixelType::Float, ExrPixelType::Half] {
+            let image = decode_exr(&encode_exr(&exr_canvas(), pixel_type).unwrap());
+
+            assert_eq!(image.dimensions(), (3, 2));
+            assert_eq!(*image.get_pixel(1, 1), image::Rgb([100.0, 0.25, 4.5]));
+            assert_eq!(*image.get_pixel(2, 1), image::Rgb([0.0, 0.0, 0.0]));
+        }
+    }
+
+    #[test]
+    fn exr_half_samples_are_rounded() {
+        let mut c = Canvas::new(1, 1);
+        c[[0, 0]] = Color::new(0.1, 1e6, f64::INFINITY);
+
+        let half = decode_exr(&encode_exr(&c, ExrPixelType::Half).unwrap());
+        let float = decode_exr(&encode_exr(&c, ExrPixelType::Float).unwrap());
+
+        assert_eq!(
+            half.get_pixel(0, 0).0,
+            [f16::from_f64(0.1).to_f32(), f32::INFINITY, f32::INFINITY]
+        );
+        assert_eq!(float.get_pixel(0, 0).0, [0.1, 1e6, f32::INFINITY]);
+    }
+
+    #[test]
+    fn empty_canvas_cannot_be_encoded() {
+        for c in [Canvas::new(0, 0), Canvas::new(4, 0), Canvas::new(0, 4)] {
+            assert!(matches!(encode_hdr(&c), Err(ImageError::Parameter(_))));
+            for pixel_type in [ExrPixelType::Float, ExrPixelType::Half] {
+                assert!(matches!(
+                    encode_exr(&c, pixel_type),
+                    Err(ImageError::Parameter(_))
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn writing_hdr_and_exr_files() {
+        let c = exr_canvas();
+        let hdr = temp_path("writing_hdr_and_exr_files.hdr");
+        let exr = temp_path("writing_hdr_and_exr_files.exr");
+
+        write_hdr(&c, &hdr).unwrap();
+        write_exr(&c, &exr, ExrPixelType::Half).unwrap();
+        let hdr_bytes = fs::read(&hdr).unwrap();
+        let exr_bytes = fs::read(&exr).unwrap();
+        fs::remove_file(&hdr).unwrap();
+        fs::remove_file(&exr).unwrap();
+
+        assert_eq!(hdr_bytes, encode_hdr(&c).unwrap());
+        assert_eq!(exr_bytes, encode_exr(&c, ExrPixelType::Half).unwrap());
+    }
 }
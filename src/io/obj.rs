@@ -0,0 +1,427 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    core::{point::Point, vector::Vector},
+    shapes::{group::Group, shape::Shape, smooth_triangle::SmoothTriangle, triangle::Triangle},
+};
+
+/// Geometry read from a Wavefront OBJ file.
+///
+/// Faces before any `g` or `o` statement end up in `default_group`, the rest
+/// in the named group they were declared under. Polygons are split into a fan
+/// of triangles, which are smooth whenever the face references vertex normals.
+#[derive(Debug, Default)]
+pub struct ObjFile {
+    pub vertices: Vec<Point>,
+    pub normals: Vec<Vector>,
+    pub texture_coords: Vec<(f64, f64)>,
+    pub default_group: Group,
+    pub groups: Vec<(String, Group)>,
+    /// Lines that were not understood, either unsupported statements or
+    /// malformed ones.
+    pub ignored_lines: usize,
+}
+
+impl ObjFile {
+    pub fn group(&self, name: &str) -> Option<&Group> {
+        self.groups
+            .iter()
+            .find(|(group_name, _)| group_name == name)
+            .map(|(_, group)| group)
+    }
+
+    /// Gathers every face into a single group, with each named group as a
+    /// child group.
+    pub fn into_group(self) -> Group {
+        let mut group = self.default_group;
+
+        for (_, child) in self.groups {
+            group.add_child(child);
+        }
+
+        group
+    }
+
+    fn parse_line(&mut self, line: &str, current_group: &mut Option<usize>) -> Option<()> {
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next()? {
+            "v" => {
+                // the optional weight only matters for rational curves
+                let [x, y, z, _w] = parse_floats(tokens, 3)?;
+                self.vertices.push(Point::new(x, y, z));
+            }
+            "vn" => {
+                let [x, y, z] = parse_floats(tokens, 3)?;
+                self.normals.push(Vector::new(x, y, z));
+            }
+            "vt" => {
+                let [u, v, _w] = parse_floats(tokens, 1)?;
+                self.texture_coords.push((u, v));
+            }
+            "f" => {
+                let triangles = self.parse_face(tokens)?;
+                let group = match current_group {
+                    Some(index) => &mut self.groups[*index].1,
+                    None => &mut self.default_group,
+                };
+
+                for triangle in triangles {
+                    group.add_boxed_child(triangle);
+                }
+            }
+            "g" | "o" => {
+                let name = tokens.collect::<Vec<_>>().join(" ");
+
+                let index = match self.groups.iter().position(|(n, _)| *n == name) {
+                    Some(index) => index,
+                    None => {
+                        self.groups.push((name, Group::new()));
+                        self.groups.len() - 1
+                    }
+                };
+
+                *current_group = Some(index);
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    fn parse_face<'a>(&self, tokens: impl Iterator<Item = &'a str>) -> Option<Vec<Box<dyn Shape>>> {
+        let mut points = vec![];
+        let mut normals = vec![];
+
+        for token in tokens {
+            // v, v/vt, v//vn or v/vt/vn
+            let mut indices = token.split('/');
+
+            points.push(*resolve(&self.vertices, indices.next()?)?);
+
+            // texture coordinates aren't used yet, but must still exist
+            match indices.next() {
+                Some(index) if !index.is_empty() => {
+                    resolve(&self.texture_coords, index)?;
+                }
+                _ => {}
+            }
+
+            match indices.next() {
+                Some(index) if !index.is_empty() => normals.push(*resolve(&self.normals, index)?),
+                _ => {}
+            }
+        }
+
+        if points.len() < 3 || (!normals.is_empty() && normals.len() != points.len()) {
+            return None;
+        }
+
+        let triangles = (1..points.len() - 1)
+            .map(|i| -> Box<dyn Shape> {
+                if normals.is_empty() {
+                    Box::new(Triangle::new(points[0], points[i], points[i + 1]))
+                } else {
+                    Box::new(SmoothTriangle::new(
+                        points[0],
+                        points[i],
+                        points[i + 1],
+                        normals[0],
+                        normals[i],
+                        normals[i + 1],
+                    ))
+                }
+            })
+            .collect();
+
+        Some(triangles)
+    }
+}
+
+/// Parses at least `required` and at most `N` numbers, with any optional
+/// ones that are left out set to 0.
+fn parse_floats<'a, const N: usize>(
+    tokens: impl Iterator<Item = &'a str>,
+    required: usize,
+) -> Option<[f64; N]> {
+    let mut values = [0.0; N];
+    let mut count = 0;
+
+    for token in tokens {
+        *values.get_mut(count)? = token.parse().ok()?;
+        count += 1;
+    }
+
+    (count >= required).then_some(values)
+}
+
+/// Looks up a 1-based OBJ index, where negative values count back from the
+/// last element read so far.
+fn resolve<'a, T>(elements: &'a [T], index: &str) -> Option<&'a T> {
+    let index: i64 = index.parse().ok()?;
+
+    let position = match index {
+        i if i > 0 => i - 1,
+        i if i < 0 => elements.len() as i64 + i,
+        _ => return None,
+    };
+
+    elements.get(usize::try_from(position).ok()?)
+}
+
+pub fn parse_obj(input: &str) -> ObjFile {
+    let mut obj = ObjFile::default();
+    let mut current_group = None;
+
+    for line in input.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if obj.parse_line(line, &mut current_group).is_none() {
+            obj.ignored_lines += 1;
+        }
+    }
+
+    obj
+}
+
+pub fn read_obj(path: impl AsRef<Path>) -> io::Result<ObjFile> {
+    Ok(parse_obj(&fs::read_to_string(path)?))
+}
+
+#[cfg(test)]
+mod obj_tests {
+    use crate::ray::Ray;
+
+    use super::*;
+
+    #[test]
+    fn ignoring_unrecognized_lines() {
+        let gibberish = "There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.";
+
+        let obj = parse_obj(gibberish);
+
+        assert_eq!(obj.ignored_lines, 5);
+    }
+
+    #[test]
+    fn skipping_comments_and_blank_lines() {
+        let obj = parse_obj("# a comment\n\n   \nv 1 2 3\n");
+
+        assert_eq!(obj.ignored_lines, 0);
+        assert_eq!(obj.vertices.len(), 1);
+    }
+
+    #[test]
+    fn vertex_records() {
+        let file = "v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0 0.5";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.ignored_lines, 0);
+        assert_eq!(obj.vertices[0], Point::new(-1.0, 1.0, 0.0));
+        assert_eq!(obj.vertices[1], Point::new(-1.0, 0.5, 0.0));
+        assert_eq!(obj.vertices[2], Point::new(1.0, 0.0, 0.0));
+        assert_eq!(obj.vertices[3], Point::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn malformed_vertex_records_are_ignored() {
+        let obj = parse_obj("v 1 2\nv 1 2 x\nv 1 2 3 4 5");
+
+        assert_eq!(obj.ignored_lines, 3);
+        assert!(obj.vertices.is_empty());
+    }
+
+    #[test]
+    fn parsing_triangle_faces() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.ignored_lines, 0);
+        assert_eq!(obj.default_group.len(), 2);
+
+        // (0.5, 0.75) only lies in the second triangle
+        let r = Ray::from(Point::new(0.5, 0.75, -1.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(obj.default_group.children()[0].intersect(&r).is_empty());
+        assert_eq!(obj.default_group.children()[1].intersect(&r).len(), 1);
+    }
+
+    #[test]
+    fn triangulating_polygons() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+
+f 1 2 3 4 5";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.default_group.len(), 3);
+
+        // the tip of the pentagon belongs to the last triangle of the fan
+        let r = Ray::from(Point::new(0.0, 1.5, -1.0), Vector::new(0.0, 0.0, 1.0));
+        let children = obj.default_group.children();
+
+        assert!(children[0].intersect(&r).is_empty());
+        assert!(children[1].intersect(&r).is_empty());
+        assert_eq!(children[2].intersect(&r).len(), 1);
+    }
+
+    #[test]
+    fn negative_indices_count_back_from_last_vertex() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+f -3 -2 -1";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.ignored_lines, 0);
+        assert_eq!(obj.default_group.len(), 1);
+    }
+
+    #[test]
+    fn faces_referencing_missing_vertices_are_ignored() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+f 1 2 4
+f 0 1 2
+f 1 2";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.ignored_lines, 3);
+        assert!(obj.default_group.is_empty());
+    }
+
+    #[test]
+    fn triangles_in_named_groups() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+g FirstGroup
+f 1 2 3
+o SecondGroup
+f 1 3 4
+g FirstGroup
+f 1 2 4";
+
+        let obj = parse_obj(file);
+
+        assert!(obj.default_group.is_empty());
+        assert_eq!(obj.group("FirstGroup").unwrap().len(), 2);
+        assert_eq!(obj.group("SecondGroup").unwrap().len(), 1);
+        assert!(obj.group("ThirdGroup").is_none());
+    }
+
+    #[test]
+    fn converting_obj_file_to_group() {
+        let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+f 1 2 4
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+
+        let group = parse_obj(file).into_group();
+
+        assert_eq!(group.len(), 3);
+    }
+
+    #[test]
+    fn vertex_normal_and_texture_records() {
+        let file = "vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3
+vt 0.5 0.25
+vt 0 1 0
+vt 0.75
+vt
+vt 1 2 3 4";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.ignored_lines, 2);
+        assert_eq!(obj.normals[0], Vector::new(0.0, 0.0, 1.0));
+        assert_eq!(obj.normals[1], Vector::new(0.707, 0.0, -0.707));
+        assert_eq!(obj.normals[2], Vector::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            obj.texture_coords,
+            vec![(0.5, 0.25), (0.0, 1.0), (0.75, 0.0)]
+        );
+    }
+
+    #[test]
+    fn faces_with_normals_are_smooth() {
+        let file = "v 0 1 0
+v -1 0 0
+v 1 0 0
+
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+
+vt 0 0
+vt 1 0
+vt 0.5 1
+
+f 1//3 2//1 3//2
+f 1/1/3 2/2/1 3/3/2";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.ignored_lines, 0);
+        assert_eq!(obj.default_group.len(), 2);
+
+        // a flat triangle would have a (0, 0, -1) normal
+        let r = Ray::from(Point::new(-0.2, 0.3, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let child = obj.default_group.children()[0].as_ref();
+        let xs = child.intersect(&r);
+        let comps = xs[0].prepare_computations(&r, &xs);
+
+        assert_eq!(comps.normalv, Vector::new(-0.5547, 0.83205, 0.0));
+    }
+
+    #[test]
+    fn faces_referencing_missing_texture_coords_are_ignored() {
+        let file = "v 0 1 0
+v -1 0 0
+v 1 0 0
+vn 0 0 1
+vt 0 0
+f 1/1 2/1 3/2
+f 1/0/1 2/1/1 3/1/1
+f 1/1/1 2/1/1 3/1/1";
+
+        let obj = parse_obj(file);
+
+        assert_eq!(obj.ignored_lines, 2);
+        assert_eq!(obj.default_group.len(), 1);
+    }
+}
@@ -0,0 +1,409 @@
+use std::{fs, io, path::Path};
+
+use crate::core::{canvas::Canvas, color::Color};
+
+/// Longest line allowed in a plain PPM file.
+const MAX_LINE_LENGTH: usize = 70;
+
+impl Canvas {
+    /// The canvas as a plain text (P3) PPM file, with 8 bit samples.
+    ///
+    /// Each row starts on a new line and long rows are wrapped so no line
+    /// is over 70 characters.
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = format!("P3\n{} {}\n255\n", self.width(), self.height());
+
+        for row in self.rows() {
+            let mut line = String::new();
+
+            for sample in row.iter().flat_map(|color| color.to_rgb().0) {
+                let sample = sample.to_string();
+
+                if line.len() + 1 + sample.len() > MAX_LINE_LENGTH {
+                    ppm.push_str(&line);
+                    ppm.push('\n');
+                    line.clear();
+                }
+
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(&sample);
+            }
+
+            ppm.push_str(&line);
+            ppm.push('\n');
+        }
+
+        ppm
+    }
+
+    /// The canvas as a binary (P6) PPM file, with 8 bit samples.
+    pub fn to_ppm_binary(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        ppm.extend(self.pixels().iter().flat_map(|color| color.to_rgb().0));
+
+        ppm
+    }
+}
+
+pub fn write_ppm(canvas: &Canvas, path: impl AsRef<Path>) -> io::Result<()> {
+    fs::write(path, canvas.to_ppm())
+}
+
+pub fn write_ppm_binary(canvas: &Canvas, path: impl AsRef<Path>) -> io::Result<()> {
+    fs::write(path, canvas.to_ppm_binary())
+}
+
+/// Reads a plain (P3) or binary (P6) PPM file.
+///
+/// Samples are divided by the maximum value declared in the header, so they
+/// come out between 0 and 1 whatever the bit depth.
+pub fn parse_ppm(input: &[u8]) -> io::Result<Canvas> {
+    let mut reader = Reader { input, position: 0 };
+
+    let binary = match reader.token()? {
+        b"P3" => false,
+        b"P6" => true,
+        _ => return Err(invalid_data("not a P3 or P6 PPM file")),
+    };
+    let width = reader.number()?;
+    let height = reader.number()?;
+    let maxval = reader.number()?;
+
+    if maxval == 0 || maxval > 65535 {
+        return Err(invalid_data("maximum value must be between 1 and 65535"));
+    }
+
+    let count = width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(3))
+        .ok_or_else(|| invalid_data("image is too large"))?;
+
+    let samples = if binary {
+        reader.binary_samples(count, maxval)?
+    } else {
+        (0..count)
+            .map(|_| reader.number())
+            .collect::<io::Result<_>>()?
+    };
+
+    let pixels = samples
+        .chunks_exact(3)
+        .map(|rgb| {
+            let scale = |sample: usize| sample.min(maxval) as f64 / maxval as f64;
+
+            Color::new(scale(rgb[0]), scale(rgb[1]), scale(rgb[2]))
+        })
+        .collect();
+
+    Canvas::from_pixels(width, height, pixels).ok_or_else(|| invalid_data("image is too large"))
+}
+
+pub fn read_ppm(path: impl AsRef<Path>) -> io::Result<Canvas> {
+    parse_ppm(&fs::read(path)?)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Walks through the whitespace separated tokens of a PPM file, skipping
+/// comments.
+struct Reader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(byte) = self.peek() {
+            if byte == b'#' {
+                while matches!(self.peek(), Some(b) if b != b'\n') {
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn token(&mut self) -> io::Result<&'a [u8]> {
+        self.skip_whitespace_and_comments();
+
+        let start = self.position;
+        while matches!(self.peek(), Some(b) if !b.is_ascii_whitespace() && b != b'#') {
+            self.position += 1;
+        }
+
+        if start == self.position {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "PPM file ends early",
+            ));
+        }
+
+        Ok(&self.input[start..self.position])
+    }
+
+    fn number(&mut self) -> io::Result<usize> {
+        std::str::from_utf8(self.token()?)
+            .ok()
+            .and_then(|token| token.parse().ok())
+            .ok_or_else(|| invalid_data("expected a number"))
+    }
+
+    /// Reads `count` raw samples, one byte each, or two big endian bytes
+    /// when `maxval` doesn't fit in one.
+    fn binary_samples(&mut self, count: usize, maxval: usize) -> io::Result<Vec<usize>> {
+        // a single whitespace character separates the header from the data
+        self.position += 1;
+
+        let width = if maxval < 256 { 1 } else { 2 };
+        let length = count
+            .checked_mul(width)
+            .ok_or_else(|| invalid_data("image is too large"))?;
+        let data = self
+            .input
+            .get(self.position..)
+            .filter(|data| data.len() >= length)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "PPM file ends early"))?;
+
+        Ok(data
+            .chunks_exact(width)
+            .take(count)
+            .map(|bytes| bytes.iter().fold(0, |sample, &b| sample << 8 | b as usize))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod ppm_tests {
+    use crate::{io::image::make_image, utils::utils::temp_path};
+
+    use super::*;
+
+    #[test]
+    fn constructing_ppm_header() {
+        let c = Canvas::new(5, 3);
+
+        let ppm = c.to_ppm();
+
+        assert!(ppm.starts_with("P3\n5 3\n255\n"));
+    }
+
+    #[test]
+    fn constructing_ppm_pixel_data() {
+        let mut c = Canvas::new(5, 3);
+        c[[0, 0]] = Color::new(1.5, 0.0, 0.0);
+        c[[2, 1]] = Color::new(0.0, 0.5, 0.0);
+        c[[4, 2]] = Color::new(-0.5, 0.0, 1.0);
+
+        let ppm = c.to_ppm();
+        let lines: Vec<&str> = ppm.lines().skip(3).collect();
+
+        assert_eq!(
+            lines,
+            [
+                "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255",
+            ]
+        );
+    }
+
+    #[test]
+    fn splitting_long_lines_in_ppm() {
+        let c = Canvas::new_filled(10, 2, Color::new(1.0, 0.8, 0.6));
+
+        let ppm = c.to_ppm();
+        let lines: Vec<&str> = ppm.lines().skip(3).collect();
+
+        assert_eq!(
+            lines,
+            [
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+            ]
+        );
+        assert!(ppm.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+    }
+
+    #[test]
+    fn ppm_ends_with_newline() {
+        let c = Canvas::new(5, 3);
+
+        assert!(c.to_ppm().ends_with('\n'));
+    }
+
+    #[test]
+    fn constructing_binary_ppm() {
+        let mut c = Canvas::new(2, 1);
+        c[[1, 0]] = Color::new(1.0, 0.5, 2.0);
+
+        let ppm = c.to_ppm_binary();
+
+        assert_eq!(ppm, b"P6\n2 1\n255\n\x00\x00\x00\xff\x80\xff");
+    }
+
+    #[test]
+    fn ppm_and_image_samples_agree() {
+        let mut c = Canvas::new(3, 2);
+        c[[0, 0]] = Color::new(0.5, 0.3, 0.7);
+        c[[1, 0]] = Color::new(1.5, -0.2, 0.001);
+        c[[2, 1]] = Color::new(0.999, 0.002, 0.498);
+
+        let ppm = c.to_ppm_binary();
+        let image = make_image(&c);
+
+        assert_eq!(ppm[ppm.len() - 18..], image.into_raw()[..]);
+    }
+
+    #[test]
+    fn reading_file_with_wrong_magic_number() {
+        let error = parse_ppm(b"P32\n1 1\n255\n0 0 0\n").unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn reading_ppm_returns_canvas_of_right_size() {
+        let ppm = b"P3\n10 2\n255\n"
+            .iter()
+            .copied()
+            .chain(b"0 0 0\n".repeat(20));
+
+        let c = parse_ppm(&ppm.collect::<Vec<u8>>()).unwrap();
+
+        assert_eq!(c.width(), 10);
+        assert_eq!(c.height(), 2);
+    }
+
+    #[test]
+    fn reading_pixel_data_from_ppm() {
+        let ppm = b"P3
+4 3
+255
+255 127 0  0 127 255  127 255 0  255 255 255
+0 0 0  255 0 0  0 255 0  0 0 255
+255 255 0  0 255 255  255 0 255  127 127 127
+";
+
+        let c = parse_ppm(ppm).unwrap();
+        let cases = [
+            (0, 0, Color::new(1.0, 127.0 / 255.0, 0.0)),
+            (1, 0, Color::new(0.0, 127.0 / 255.0, 1.0)),
+            (3, 0, Color::WHITE),
+            (1, 1, Color::new(1.0, 0.0, 0.0)),
+            (2, 2, Color::new(1.0, 0.0, 1.0)),
+        ];
+
+        for (x, y, expected) in cases {
+            assert_eq!(c[[x, y]], expected);
+        }
+    }
+
+    #[test]
+    fn ppm_parsing_ignores_comment_lines() {
+        let ppm = b"P3
+# this is a comment
+2 1
+# this, too
+255
+# another comment
+255 255 255
+# oh, no, comments in the pixel data!
+255 0 255
+";
+
+        let c = parse_ppm(ppm).unwrap();
+
+        assert_eq!(c[[0, 0]], Color::WHITE);
+        assert_eq!(c[[1, 0]], Color::new(1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn ppm_parsing_allows_rgb_triple_to_span_lines() {
+        let ppm = b"P3\n1 1\n255\n51\n153\n\n204\n";
+
+        let c = parse_ppm(ppm).unwrap();
+
+        assert_eq!(c[[0, 0]], Color::new(0.2, 0.6, 0.8));
+    }
+
+    #[test]
+    fn ppm_parsing_respects_scale_setting() {
+        let ppm = b"P3\n2 2\n100\n100 100 100  50 50 50\n75 50 25  0 0 0\n";
+
+        let c = parse_ppm(ppm).unwrap();
+
+        assert_eq!(c[[0, 1]], Color::new(0.75, 0.5, 0.25));
+    }
+
+    #[test]
+    fn reading_binary_ppm() {
+        let ppm = b"P6 # binary\n2 1\n255\n\x00\x00\x00\xff\x33\x0a";
+
+        let c = parse_ppm(ppm).unwrap();
+
+        assert_eq!(c[[0, 0]], Color::BLACK);
+        assert_eq!(c[[1, 0]], Color::new(1.0, 0.2, 10.0 / 255.0));
+    }
+
+    #[test]
+    fn reading_binary_ppm_with_16_bit_samples() {
+        let ppm = b"P6\n1 1\n1000\n\x03\xe8\x01\xf4\x00\x00";
+
+        let c = parse_ppm(ppm).unwrap();
+
+        assert_eq!(c[[0, 0]], Color::new(1.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn reading_truncated_ppm() {
+        let plain = parse_ppm(b"P3\n2 1\n255\n0 0 0 0\n").unwrap_err();
+        let binary = parse_ppm(b"P6\n2 1\n255\n\x00\x00\x00\x00").unwrap_err();
+
+        assert_eq!(plain.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(binary.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn reading_ppm_too_large_to_address() {
+        let huge = format!("P6\n{0} {0}\n255\n", 1usize << (usize::BITS / 2));
+        let wide = format!("P6\n{} 1\n65535\n", usize::MAX / 4);
+
+        for header in [huge, wide] {
+            let error = parse_ppm(header.as_bytes()).unwrap_err();
+
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn ppm_round_trip_through_files() {
+        let mut c = Canvas::new(3, 2);
+        c[[0, 0]] = Color::WHITE;
+        c[[2, 1]] = Color::new(1.0, 0.0, 0.0);
+        let plain = temp_path("ppm_round_trip_plain.ppm");
+        let binary = temp_path("ppm_round_trip_binary.ppm");
+
+        write_ppm(&c, &plain).unwrap();
+        write_ppm_binary(&c, &binary).unwrap();
+        let from_plain = read_ppm(&plain).unwrap();
+        let from_binary = read_ppm(&binary).unwrap();
+        fs::remove_file(&plain).unwrap();
+        fs::remove_file(&binary).unwrap();
+
+        assert_eq!(from_plain, c);
+        assert_eq!(from_binary, c);
+    }
+}
@@ -9,12 +9,60 @@ pub mod core {
     pub mod vector;
 }
 
+pub mod camera;
+pub mod intersection;
+
+pub mod lights {
+    pub mod area_light;
+    pub mod directional_light;
+    pub mod light;
+    pub mod point_light;
+    pub mod spot_light;
+}
+
+pub mod material;
+
+pub mod patterns {
+    pub mod blend;
+    pub mod checker;
+    pub mod gradient;
+    pub mod image_texture;
+    pub mod pattern;
+    pub mod perlin;
+    pub mod perturbed;
+    pub mod ring;
+    pub mod solid;
+    pub mod stripe;
+    pub mod texture_map;
+    pub mod uv_map;
+    pub mod uv_pattern;
+}
+
 pub mod ray;
 
+pub mod shapes {
+    pub mod bounding_box;
+    pub mod cone;
+    pub mod csg;
+    pub mod cube;
+    pub mod cylinder;
+    pub mod group;
+    pub mod plane;
+    pub mod shape;
+    pub mod smooth_triangle;
+    pub mod sphere;
+    pub mod triangle;
+}
+
+pub mod world;
+
 pub mod utils {
+    #[allow(clippy::module_inception)]
     pub mod utils;
 }
 
 pub mod io {
     pub mod image;
+    pub mod obj;
+    pub mod ppm;
 }
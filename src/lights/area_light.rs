@@ -0,0 +1,256 @@
+use crate::{
+    core::{color::Color, point::Point, vector::Vector},
+    utils::utils::splitmix64,
+};
+
+use super::light::{Light, LightSample};
+
+/// A rectangular light, split into a grid of cells sampled one point each,
+/// so shadows soften into a penumbra where only part of it is hidden.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AreaLight {
+    pub corner: Point,
+    /// Edge of a single cell along the first side of the light.
+    pub uvec: Vector,
+    usteps: usize,
+    /// Edge of a single cell along the second side of the light.
+    pub vvec: Vector,
+    vsteps: usize,
+    pub intensity: Color,
+    /// Whether cells are sampled at a random spot rather than their center,
+    /// trading the banding of evenly spaced samples for noise.
+    pub jitter: bool,
+    pub casts_shadows: bool,
+}
+
+impl AreaLight {
+    /// A light spanning `full_uvec` and `full_vvec` from `corner`, split into
+    /// `usteps` by `vsteps` cells.
+    ///
+    /// # Panics
+    ///
+    /// If either step count is 0, which would leave the light with no cells.
+    pub fn new(
+        corner: Point,
+        full_uvec: Vector,
+        usteps: usize,
+        full_vvec: Vector,
+        vsteps: usize,
+        intensity: Color,
+    ) -> Self {
+        assert!(
+            usteps > 0 && vsteps > 0,
+            "area light needs at least one cell along each edge, got {usteps}x{vsteps}"
+        );
+
+        AreaLight {
+            corner,
+            uvec: full_uvec / usteps as f64,
+            usteps,
+            vvec: full_vvec / vsteps as f64,
+            vsteps,
+            intensity,
+            jitter: false,
+            casts_shadows: true,
+        }
+    }
+
+    pub fn usteps(&self) -> usize {
+        self.usteps
+    }
+
+    pub fn vsteps(&self) -> usize {
+        self.vsteps
+    }
+
+    pub fn samples_count(&self) -> usize {
+        self.usteps * self.vsteps
+    }
+
+    /// Center of the light.
+    pub fn position(&self) -> Point {
+        self.corner
+            + self.uvec * (self.usteps as f64 / 2.0)
+            + self.vvec * (self.vsteps as f64 / 2.0)
+    }
+
+    /// Center of the cell `u` steps along the first edge and `v` steps
+    /// along the second.
+    pub fn point_on_light(&self, u: usize, v: usize) -> Point {
+        self.corner + self.uvec * (u as f64 + 0.5) + self.vvec * (v as f64 + 0.5)
+    }
+
+    /// The spot sampled in cell `u`, `v` when lighting `point`.
+    ///
+    /// Jittered spots are hashed from the lit point and the cell instead of
+    /// drawn from a shared generator, so renders are reproducible no matter
+    /// the order pixels are shaded in.
+    fn sample_point(&self, u: usize, v: usize, point: Point) -> Point {
+        if !self.jitter {
+            return self.point_on_light(u, v);
+        }
+
+        let seed = hash(&[
+            point.x.to_bits(),
+            point.y.to_bits(),
+            point.z.to_bits(),
+            u as u64,
+            v as u64,
+        ]);
+        let du = unit(seed);
+        let dv = unit(hash(&[seed]));
+
+        self.corner + self.uvec * (u as f64 + du) + self.vvec * (v as f64 + dv)
+    }
+}
+
+impl Light for AreaLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn casts_shadows(&self) -> bool {
+        self.casts_shadows
+    }
+
+    fn samples(&self, point: Point) -> Vec<LightSample> {
+        let mut samples = Vec::with_capacity(self.samples_count());
+
+        for v in 0..self.vsteps {
+            for u in 0..self.usteps {
+                let to_light = self.sample_point(u, v, point) - point;
+
+                samples.push(LightSample {
+                    lightv: to_light.normalize(),
+                    distance: to_light.magnitude(),
+                    intensity: self.intensity,
+                });
+            }
+        }
+
+        samples
+    }
+}
+
+/// Mixes `values` into a well-spread 64 bit hash.
+fn hash(values: &[u64]) -> u64 {
+    values
+        .iter()
+        .fold(0, |hash, &value| splitmix64(&mut (hash ^ value)))
+}
+
+/// Maps a hash to a number in `[0, 1)`.
+fn unit(hash: u64) -> f64 {
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod area_light_tests {
+    use super::*;
+
+    fn light() -> AreaLight {
+        AreaLight::new(
+            Point::new(0.0, 0.0, 0.0),
+            Vector::new(2.0, 0.0, 0.0),
+            4,
+            Vector::new(0.0, 0.0, 1.0),
+            2,
+            Color::WHITE,
+        )
+    }
+
+    #[test]
+    fn creating_area_light() {
+        let light = light();
+
+        assert_eq!(light.corner, Point::new(0.0, 0.0, 0.0));
+        assert_eq!(light.uvec, Vector::new(0.5, 0.0, 0.0));
+        assert_eq!(light.usteps, 4);
+        assert_eq!(light.vvec, Vector::new(0.0, 0.0, 0.5));
+        assert_eq!(light.vsteps, 2);
+        assert_eq!(light.samples_count(), 8);
+        assert_eq!(light.position(), Point::new(1.0, 0.0, 0.5));
+        assert!(!light.jitter);
+    }
+
+    #[test]
+    #[should_panic(expected = "area light needs at least one cell along each edge, got 4x0")]
+    fn creating_area_light_without_cells_panics() {
+        AreaLight::new(
+            Point::new(0.0, 0.0, 0.0),
+            Vector::new(2.0, 0.0, 0.0),
+            4,
+            Vector::new(0.0, 0.0, 1.0),
+            0,
+            Color::WHITE,
+        );
+    }
+
+    #[test]
+    fn finding_single_point_on_area_light() {
+        let light = light();
+        let cases = [
+            (0, 0, Point::new(0.25, 0.0, 0.25)),
+            (1, 0, Point::new(0.75, 0.0, 0.25)),
+            (0, 1, Point::new(0.25, 0.0, 0.75)),
+            (2, 0, Point::new(1.25, 0.0, 0.25)),
+            (3, 1, Point::new(1.75, 0.0, 0.75)),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(light.point_on_light(u, v), expected);
+        }
+    }
+
+    #[test]
+    fn area_light_is_sampled_once_per_cell() {
+        let light = light();
+        let point = Point::new(1.0, 2.0, 0.5);
+
+        let samples = light.samples(point);
+
+        assert_eq!(samples.len(), 8);
+        assert_eq!(
+            samples[0].distance,
+            (light.point_on_light(0, 0) - point).magnitude()
+        );
+        assert_eq!(
+            samples[7].lightv,
+            (light.point_on_light(3, 1) - point).normalize()
+        );
+    }
+
+    #[test]
+    fn jittered_samples_stay_within_their_cells() {
+        let light = AreaLight {
+            jitter: true,
+            ..light()
+        };
+        let point = Point::new(1.0, 2.0, 0.5);
+
+        for v in 0..light.vsteps {
+            for u in 0..light.usteps {
+                let p = light.sample_point(u, v, point);
+
+                assert!(p.x >= u as f64 * 0.5 && p.x < (u + 1) as f64 * 0.5);
+                assert!(p.z >= v as f64 * 0.5 && p.z < (v + 1) as f64 * 0.5);
+                assert_ne!(p, light.point_on_light(u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn jittered_samples_are_reproducible() {
+        let light = AreaLight {
+            jitter: true,
+            ..light()
+        };
+        let point = Point::new(1.0, 2.0, 0.5);
+
+        assert_eq!(light.samples(point), light.samples(point));
+        assert_ne!(
+            light.samples(point),
+            light.samples(Point::new(1.0, 2.0, 0.6))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::core::{color::Color, point::Point, vector::Vector};
+
+use super::light::{Light, LightSample};
+
+/// A light so far away that its rays arrive parallel, like the sun. It
+/// lights every point in the scene from the same direction, with the same
+/// intensity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectionalLight {
+    /// Direction the light travels in, from the light into the scene.
+    pub direction: Vector,
+    pub intensity: Color,
+    pub casts_shadows: bool,
+}
+
+impl DirectionalLight {
+    pub fn new(direction: Vector, intensity: Color) -> Self {
+        DirectionalLight {
+            direction: direction.normalize(),
+            intensity,
+            casts_shadows: true,
+        }
+    }
+}
+
+impl Light for DirectionalLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn casts_shadows(&self) -> bool {
+        self.casts_shadows
+    }
+
+    fn samples(&self, _point: Point) -> Vec<LightSample> {
+        vec![LightSample {
+            lightv: -self.direction,
+            distance: f64::INFINITY,
+            intensity: self.intensity,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod directional_light_tests {
+    use super::*;
+
+    #[test]
+    fn creating_directional_light_normalizes_direction() {
+        let light = DirectionalLight::new(Vector::new(0.0, -3.0, 4.0), Color::WHITE);
+
+        assert_eq!(light.direction, Vector::new(0.0, -0.6, 0.8));
+        assert!(light.casts_shadows);
+    }
+
+    #[test]
+    fn directional_light_comes_from_same_direction_everywhere() {
+        let light = DirectionalLight::new(Vector::new(0.0, -1.0, 0.0), Color::WHITE);
+
+        for point in [Point::new(0.0, 0.0, 0.0), Point::new(100.0, -5.0, 3.0)] {
+            let samples = light.samples(point);
+
+            assert_eq!(samples.len(), 1);
+            assert_eq!(samples[0].lightv, Vector::new(0.0, 1.0, 0.0));
+            assert_eq!(samples[0].distance, f64::INFINITY);
+            assert_eq!(samples[0].intensity, Color::WHITE);
+        }
+    }
+}
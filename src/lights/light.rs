@@ -0,0 +1,289 @@
+use std::fmt::Debug;
+
+use crate::{
+    core::{color::Color, point::Point, vector::Vector},
+    material::Material,
+    shapes::shape::Shape,
+    utils::utils::DynEq,
+};
+
+/// Light arriving at a point from one spot on a light source.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightSample {
+    /// Unit vector from the lit point towards the light.
+    pub lightv: Vector,
+    /// How far the light is, infinite for lights like the sun. Only objects
+    /// closer than this cast shadows.
+    pub distance: f64,
+    /// Color and brightness of the light reaching the point.
+    pub intensity: Color,
+}
+
+/// A light source.
+///
+/// Lights with a size are sampled at several spots, each shaded and tested
+/// for shadows on its own, so objects cast soft shadows.
+pub trait Light: Debug + Send + Sync + DynEq {
+    /// Color and brightness of the light, used for the ambient term.
+    fn intensity(&self) -> Color;
+
+    /// Whether objects block this light. Fill lights usually turn this off.
+    fn casts_shadows(&self) -> bool;
+
+    /// The light reaching `point` from every spot the light is sampled at,
+    /// always at least one.
+    fn samples(&self, point: Point) -> Vec<LightSample>;
+}
+
+impl PartialEq for dyn Light {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_any())
+    }
+}
+
+/// Shades `point` on `object` with the Phong reflection model, as seen
+/// along `eyev`.
+///
+/// The diffuse and specular terms are averaged over the samples of the
+/// light and scaled by `intensity`, the fraction of the light that isn't
+/// blocked, so points in full shadow only receive ambient light.
+pub fn lighting(
+    material: &Material,
+    object: &dyn Shape,
+    light: &dyn Light,
+    point: Point,
+    eyev: Vector,
+    normalv: Vector,
+    intensity: f64,
+) -> Color {
+    let color = material.pattern.pattern_at_shape(object, point);
+    let ambient = color * light.intensity() * material.ambient;
+
+    if intensity == 0.0 {
+        return ambient;
+    }
+
+    let samples = light.samples(point);
+    let sum = samples.iter().fold(Color::BLACK, |sum, sample| {
+        sum + diffuse_and_specular(material, color, sample, eyev, normalv)
+    });
+
+    ambient + sum / samples.len() as f64 * intensity
+}
+
+fn diffuse_and_specular(
+    material: &Material,
+    color: Color,
+    sample: &LightSample,
+    eyev: Vector,
+    normalv: Vector,
+) -> Color {
+    let light_dot_normal = sample.lightv * normalv;
+
+    // light is on the other side of the surface
+    if light_dot_normal < 0.0 {
+        return Color::BLACK;
+    }
+
+    let diffuse = color * sample.intensity * material.diffuse * light_dot_normal;
+
+    let reflectv = (-sample.lightv).reflect(&normalv);
+    let reflect_dot_eye = reflectv * eyev;
+
+    let specular = if reflect_dot_eye <= 0.0 {
+        Color::BLACK
+    } else {
+        let factor = reflect_dot_eye.powf(material.shininess);
+        sample.intensity * material.specular * factor
+    };
+
+    diffuse + specular
+}
+
+#[cfg(test)]
+mod light_tests {
+    use crate::{
+        lights::{area_light::AreaLight, point_light::PointLight},
+        patterns::stripe::Stripe,
+        shapes::sphere::Sphere,
+    };
+
+    use super::*;
+
+    #[test]
+    fn lighting_with_eye_between_light_and_surface() {
+        let m = Material::new();
+        let position = Point::new(0.0, 0.0, 0.0);
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::WHITE);
+
+        let actual = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, 1.0);
+        let expected = Color::new(1.9, 1.9, 1.9);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lighting_with_eye_between_light_and_surface_eye_offset_45_degrees() {
+        let m = Material::new();
+        let position = Point::new(0.0, 0.0, 0.0);
+        let eyev = Vector::new(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::WHITE);
+
+        let actual = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, 1.0);
+        let expected = Color::new(1.0, 1.0, 1.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lighting_with_eye_opposite_surface_light_offset_45_degrees() {
+        let m = Material::new();
+        let position = Point::new(0.0, 0.0, 0.0);
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), Color::WHITE);
+
+        let actual = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, 1.0);
+        let c = 0.1 + 0.9 * 2.0_f64.sqrt() / 2.0;
+        let expected = Color::new(c, c, c);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lighting_with_eye_in_path_of_reflection_vector() {
+        let m = Material::new();
+        let position = Point::new(0.0, 0.0, 0.0);
+        let eyev = Vector::new(0.0, -2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), Color::WHITE);
+
+        let actual = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, 1.0);
+        let c = 0.1 + 0.9 * 2.0_f64.sqrt() / 2.0 + 0.9;
+        let expected = Color::new(c, c, c);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lighting_with_light_behind_surface() {
+        let m = Material::new();
+        let position = Point::new(0.0, 0.0, 0.0);
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, 10.0), Color::WHITE);
+
+        let actual = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, 1.0);
+        let expected = Color::new(0.1, 0.1, 0.1);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lighting_with_surface_in_shadow() {
+        let m = Material::new();
+        let position = Point::new(0.0, 0.0, 0.0);
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::WHITE);
+
+        let actual = lighting(&m, &Sphere::new(), &light, position, eyev, normalv, 0.0);
+        let expected = Color::new(0.1, 0.1, 0.1);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lighting_with_pattern_applied() {
+        let m = Material {
+            ambient: 1.0,
+            diffuse: 0.0,
+            specular: 0.0,
+            ..Material::with_pattern(Stripe::new(Color::WHITE, Color::BLACK))
+        };
+        let object = Sphere::new();
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::WHITE);
+
+        let c1 = lighting(
+            &m,
+            &object,
+            &light,
+            Point::new(0.9, 0.0, 0.0),
+            eyev,
+            normalv,
+            1.0,
+        );
+        let c2 = lighting(
+            &m,
+            &object,
+            &light,
+            Point::new(1.1, 0.0, 0.0),
+            eyev,
+            normalv,
+            1.0,
+        );
+
+        assert_eq!(c1, Color::WHITE);
+        assert_eq!(c2, Color::BLACK);
+    }
+
+    #[test]
+    fn lighting_uses_light_intensity_to_attenuate_color() {
+        let m = Material {
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.0,
+            ..Material::new()
+        };
+        let object = Sphere::new();
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::WHITE);
+        let point = Point::new(0.0, 0.0, -1.0);
+        let eyev = Vector::new(0.0, 0.0, -1.0);
+        let normalv = Vector::new(0.0, 0.0, -1.0);
+
+        for (intensity, c) in [(1.0, 1.0), (0.5, 0.55), (0.0, 0.1)] {
+            let actual = lighting(&m, &object, &light, point, eyev, normalv, intensity);
+
+            assert_eq!(actual, Color::new(c, c, c));
+        }
+    }
+
+    #[test]
+    fn lighting_samples_area_light() {
+        let light = AreaLight::new(
+            Point::new(-0.5, -0.5, -5.0),
+            Vector::new(1.0, 0.0, 0.0),
+            2,
+            Vector::new(0.0, 1.0, 0.0),
+            2,
+            Color::WHITE,
+        );
+        let m = Material {
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.0,
+            ..Material::new()
+        };
+        let object = Sphere::new();
+        let eye = Point::new(0.0, 0.0, -5.0);
+        let k = std::f64::consts::FRAC_1_SQRT_2;
+        let cases = [
+            (Point::new(0.0, 0.0, -1.0), 0.9965),
+            (Point::new(0.0, k, -k), 0.62318),
+        ];
+
+        for (point, c) in cases {
+            let eyev = (eye - point).normalize();
+            let normalv = Vector::new(point.x, point.y, point.z);
+
+            let actual = lighting(&m, &object, &light, point, eyev, normalv, 1.0);
+
+            assert_eq!(actual, Color::new(c, c, c));
+        }
+    }
+}
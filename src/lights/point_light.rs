@@ -0,0 +1,72 @@
+use crate::core::{color::Color, point::Point};
+
+use super::light::{Light, LightSample};
+
+/// A light source with no size, radiating equally in every direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointLight {
+    pub position: Point,
+    pub intensity: Color,
+    pub casts_shadows: bool,
+}
+
+impl PointLight {
+    pub fn new(position: Point, intensity: Color) -> Self {
+        PointLight {
+            position,
+            intensity,
+            casts_shadows: true,
+        }
+    }
+}
+
+impl Light for PointLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn casts_shadows(&self) -> bool {
+        self.casts_shadows
+    }
+
+    fn samples(&self, point: Point) -> Vec<LightSample> {
+        let v = self.position - point;
+
+        vec![LightSample {
+            lightv: v.normalize(),
+            distance: v.magnitude(),
+            intensity: self.intensity,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod point_light_tests {
+    use crate::core::vector::Vector;
+
+    use super::*;
+
+    #[test]
+    fn point_light_has_position_and_intensity() {
+        let intensity = Color::WHITE;
+        let position = Point::new(0.0, 0.0, 0.0);
+
+        let light = PointLight::new(position, intensity);
+
+        assert_eq!(light.position, position);
+        assert_eq!(light.intensity, intensity);
+        assert!(light.casts_shadows);
+    }
+
+    #[test]
+    fn point_light_is_sampled_at_its_position() {
+        let light = PointLight::new(Point::new(0.0, 3.0, 4.0), Color::WHITE);
+
+        let samples = light.samples(Point::new(0.0, 0.0, 0.0));
+
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].lightv, Vector::new(0.0, 0.6, 0.8));
+        assert_eq!(samples[0].distance, 5.0);
+        assert_eq!(samples[0].intensity, Color::WHITE);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::core::{color::Color, point::Point, vector::Vector};
+
+use super::light::{Light, LightSample};
+
+/// A point light shining a cone of light in one direction, like a stage
+/// spot or a flashlight.
+///
+/// Points within `inner_angle` of the axis get the full intensity, points
+/// past `outer_angle` get none, and the edge of the cone fades smoothly in
+/// between. Angles are measured from the axis, in radians.
+///
+/// The cone only shapes the diffuse and specular light. Ambient light stands
+/// in for light bounced around the scene, so a spot light adds its full
+/// ambient term everywhere, outside the cone too.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpotLight {
+    pub position: Point,
+    /// Axis of the cone, pointing away from the light.
+    pub direction: Vector,
+    pub inner_angle: f64,
+    pub outer_angle: f64,
+    pub intensity: Color,
+    pub casts_shadows: bool,
+}
+
+impl SpotLight {
+    pub fn new(
+        position: Point,
+        direction: Vector,
+        inner_angle: f64,
+        outer_angle: f64,
+        intensity: Color,
+    ) -> Self {
+        SpotLight {
+            position,
+            direction: direction.normalize(),
+            inner_angle,
+            outer_angle,
+            intensity,
+            casts_shadows: true,
+        }
+    }
+
+    /// How much of the light reaches `point` because of the shape of the
+    /// cone, from 0 outside it to 1 inside the inner cone. The light's own
+    /// position, where the cone has no direction, gets the full light.
+    pub fn falloff(&self, point: Point) -> f64 {
+        if point == self.position {
+            return 1.0;
+        }
+
+        let cos_angle = (point - self.position).normalize() * self.direction;
+        let cos_inner = self.inner_angle.cos();
+        let cos_outer = self.outer_angle.cos();
+
+        if cos_angle >= cos_inner {
+            1.0
+        } else if cos_angle <= cos_outer {
+            0.0
+        } else {
+            let t = (cos_angle - cos_outer) / (cos_inner - cos_outer);
+
+            // smoothstep, so the edge of the cone has no visible seam
+            t * t * (3.0 - 2.0 * t)
+        }
+    }
+}
+
+impl Light for SpotLight {
+    fn intensity(&self) -> Color {
+        self.intensity
+    }
+
+    fn casts_shadows(&self) -> bool {
+        self.casts_shadows
+    }
+
+    fn samples(&self, point: Point) -> Vec<LightSample> {
+        let v = self.position - point;
+
+        vec![LightSample {
+            lightv: v.normalize(),
+            distance: v.magnitude(),
+            intensity: self.intensity * self.falloff(point),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod spot_light_tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    fn spot() -> SpotLight {
+        SpotLight::new(
+            Point::new(0.0, 10.0, 0.0),
+            Vector::new(0.0, -2.0, 0.0),
+            PI / 8.0,
+            PI / 4.0,
+            Color::WHITE,
+        )
+    }
+
+    #[test]
+    fn creating_spot_light_normalizes_direction() {
+        let light = spot();
+
+        assert_eq!(light.direction, Vector::new(0.0, -1.0, 0.0));
+        assert!(light.casts_shadows);
+    }
+
+    #[test]
+    fn full_intensity_inside_inner_cone() {
+        let light = spot();
+
+        assert_eq!(light.falloff(Point::new(0.0, 0.0, 0.0)), 1.0);
+        assert_eq!(light.falloff(Point::new(3.0, 0.0, 0.0)), 1.0);
+    }
+
+    #[test]
+    fn full_intensity_at_light_position() {
+        let light = spot();
+
+        assert_eq!(light.falloff(light.position), 1.0);
+    }
+
+    #[test]
+    fn no_light_outside_outer_cone() {
+        let light = spot();
+
+        assert_eq!(light.falloff(Point::new(11.0, 0.0, 0.0)), 0.0);
+        assert_eq!(light.falloff(Point::new(0.0, 20.0, 0.0)), 0.0);
+
+        let samples = light.samples(Point::new(0.0, 0.0, 11.0));
+        assert_eq!(samples[0].intensity, Color::BLACK);
+    }
+
+    #[test]
+    fn intensity_fades_smoothly_between_cones() {
+        let light = spot();
+        let edge = |angle: f64| Point::new(10.0 * angle.tan(), 0.0, 0.0);
+
+        let middle = light.falloff(edge(3.0 * PI / 16.0));
+        let near_inner = light.falloff(edge(PI / 8.0 + 0.01));
+        let near_outer = light.falloff(edge(PI / 4.0 - 0.01));
+
+        assert!(middle > 0.0 && middle < 1.0);
+        assert!(near_inner > middle && near_inner < 1.0);
+        assert!(near_outer < middle && near_outer > 0.0);
+    }
+
+    #[test]
+    fn spot_light_is_sampled_at_its_position() {
+        let light = spot();
+
+        let samples = light.samples(Point::new(0.0, 0.0, 0.0));
+
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].lightv, Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(samples[0].distance, 10.0);
+        assert_eq!(samples[0].intensity, Color::WHITE);
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use crate::{
+    core::color::Color,
+    patterns::{pattern::Pattern, solid::Solid},
+};
+
+/// Surface attributes for the Phong reflection model.
+#[derive(Debug, Clone)]
+pub struct Material {
+    /// Color of the surface at every point, shared between clones.
+    pub pattern: Arc<dyn Pattern>,
+    pub ambient: f64,
+    pub diffuse: f64,
+    pub specular: f64,
+    pub shininess: f64,
+    /// How much of the scene the surface mirrors, from 0 (matte) to 1 (a
+    /// perfect mirror).
+    pub reflective: f64,
+    /// How much light passes through the surface, from 0 (opaque) to 1.
+    pub transparency: f64,
+    /// How much light bends entering the material: 1 for vacuum, about 1.5
+    /// for glass.
+    pub refractive_index: f64,
+}
+
+impl Material {
+    pub fn new() -> Self {
+        Material {
+            pattern: Arc::new(Solid::new(Color::WHITE)),
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.0,
+            reflective: 0.0,
+            transparency: 0.0,
+            refractive_index: 1.0,
+        }
+    }
+
+    /// The default material with a single flat color.
+    pub fn with_color(color: Color) -> Self {
+        Material::with_pattern(Solid::new(color))
+    }
+
+    pub fn with_pattern(pattern: impl Pattern + 'static) -> Self {
+        Material {
+            pattern: Arc::new(pattern),
+            ..Material::new()
+        }
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        self.set_pattern(Solid::new(color));
+    }
+
+    pub fn set_pattern(&mut self, pattern: impl Pattern + 'static) {
+        self.pattern = Arc::new(pattern);
+    }
+}
+
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        *self.pattern == *other.pattern
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material::new()
+    }
+}
+
+#[cfg(test)]
+mod material_tests {
+    use crate::{core::point::Point, patterns::stripe::Stripe};
+
+    use super::*;
+
+    #[test]
+    fn default_material() {
+        let m = Material::new();
+
+        assert_eq!(
+            m.pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)),
+            Color::WHITE
+        );
+        assert_eq!(m.ambient, 0.1);
+        assert_eq!(m.diffuse, 0.9);
+        assert_eq!(m.specular, 0.9);
+        assert_eq!(m.shininess, 200.0);
+    }
+
+    #[test]
+    fn reflectivity_for_default_material() {
+        let m = Material::new();
+
+        assert_eq!(m.reflective, 0.0);
+    }
+
+    #[test]
+    fn transparency_and_refractive_index_for_default_material() {
+        let m = Material::new();
+
+        assert_eq!(m.transparency, 0.0);
+        assert_eq!(m.refractive_index, 1.0);
+    }
+
+    #[test]
+    fn materials_compare_their_patterns() {
+        let stripes = Stripe::new(Color::WHITE, Color::BLACK);
+
+        assert_eq!(Material::new(), Material::with_color(Color::WHITE));
+        assert_ne!(Material::new(), Material::with_color(Color::BLACK));
+        assert_eq!(
+            Material::with_pattern(stripes.clone()),
+            Material::with_pattern(stripes)
+        );
+    }
+
+    #[test]
+    fn setting_material_color_replaces_pattern() {
+        let mut m = Material::with_pattern(Stripe::new(Color::WHITE, Color::BLACK));
+
+        m.set_color(Color::new(0.5, 0.5, 0.5));
+
+        assert_eq!(m, Material::with_color(Color::new(0.5, 0.5, 0.5)));
+    }
+}
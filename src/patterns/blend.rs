@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::core::{color::Color, point::Point};
+
+use super::pattern::{Pattern, PatternProps};
+
+/// The average of two patterns, both visible at once.
+#[derive(Debug, Clone)]
+pub struct Blend {
+    props: PatternProps,
+    pub a: Arc<dyn Pattern>,
+    pub b: Arc<dyn Pattern>,
+}
+
+impl Blend {
+    pub fn new(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Self {
+        Blend {
+            props: PatternProps::new(),
+            a: Arc::new(a),
+            b: Arc::new(b),
+        }
+    }
+}
+
+impl PartialEq for Blend {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props && *self.a == *other.a && *self.b == *other.b
+    }
+}
+
+impl Pattern for Blend {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        (self.a.pattern_at(point) + self.b.pattern_at(point)) * 0.5
+    }
+}
+
+#[cfg(test)]
+mod blend_tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        core::transforms::Transforms,
+        patterns::{solid::Solid, stripe::Stripe},
+    };
+
+    use super::*;
+
+    #[test]
+    fn blend_averages_both_patterns() {
+        let pattern = Blend::new(
+            Solid::new(Color::new(1.0, 0.0, 0.5)),
+            Solid::new(Color::new(0.0, 1.0, 0.5)),
+        );
+
+        let actual = pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0));
+        let expected = Color::new(0.5, 0.5, 0.5);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn blending_crossed_stripes() {
+        let green = Color::new(0.0, 1.0, 0.0);
+        let along_x = Stripe::new(Color::WHITE, green);
+        let mut along_z = Stripe::new(Color::WHITE, green);
+        along_z.set_transform(Transforms::rotation_y(PI / 2.0));
+        let pattern = Blend::new(along_x, along_z);
+
+        // both stripes white, one of them green, both green
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.5, 0.0, -0.5)),
+            Color::WHITE
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(1.5, 0.0, -0.5)),
+            Color::new(0.5, 1.0, 0.5)
+        );
+        assert_eq!(pattern.local_pattern_at(Point::new(1.5, 0.0, 0.5)), green);
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::core::{color::Color, point::Point};
+
+use super::{
+    pattern::{Pattern, PatternProps},
+    solid::Solid,
+};
+
+/// Unit cubes alternating between two colors or patterns in all three dimensions.
+#[derive(Debug, Clone)]
+pub struct Checker {
+    props: PatternProps,
+    pub a: Arc<dyn Pattern>,
+    pub b: Arc<dyn Pattern>,
+}
+
+impl Checker {
+    pub fn new(a: Color, b: Color) -> Self {
+        Checker::nested(Solid::new(a), Solid::new(b))
+    }
+
+    /// Fills alternate cubes with two other patterns, for example a checker
+    /// whose squares are striped.
+    pub fn nested(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Self {
+        Checker {
+            props: PatternProps::new(),
+            a: Arc::new(a),
+            b: Arc::new(b),
+        }
+    }
+}
+
+impl PartialEq for Checker {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props && *self.a == *other.a && *self.b == *other.b
+    }
+}
+
+impl Pattern for Checker {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let sum = point.x.floor() + point.y.floor() + point.z.floor();
+
+        if sum.rem_euclid(2.0) == 0.0 {
+            self.a.pattern_at(point)
+        } else {
+            self.b.pattern_at(point)
+        }
+    }
+}
+
+#[cfg(test)]
+mod checker_tests {
+    use std::f64::consts::PI;
+
+    use crate::{core::transforms::Transforms, patterns::stripe::Stripe};
+
+    use super::*;
+
+    const WHITE: Color = Color::WHITE;
+    const BLACK: Color = Color::BLACK;
+
+    #[test]
+    fn checkers_repeat_in_x() {
+        let pattern = Checker::new(WHITE, BLACK);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.99, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(1.01, 0.0, 0.0)), BLACK);
+    }
+
+    #[test]
+    fn checkers_repeat_in_y() {
+        let pattern = Checker::new(WHITE, BLACK);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.99, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 1.01, 0.0)), BLACK);
+    }
+
+    #[test]
+    fn checkers_repeat_in_z() {
+        let pattern = Checker::new(WHITE, BLACK);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.99)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 1.01)), BLACK);
+    }
+
+    #[test]
+    fn checkers_alternate_across_negative_coordinates() {
+        let pattern = Checker::new(WHITE, BLACK);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(-0.5, 0.5, 0.5)), BLACK);
+        assert_eq!(pattern.local_pattern_at(Point::new(-0.5, -0.5, 0.5)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(-1.5, -0.5, 0.5)), BLACK);
+    }
+
+    #[test]
+    fn checker_with_striped_squares() {
+        let red = Color::new(1.0, 0.0, 0.0);
+        let blue = Color::new(0.0, 0.0, 1.0);
+        let mut vertical = Stripe::new(WHITE, red);
+        vertical.set_transform(Transforms::scaling(0.25, 1.0, 1.0));
+        let mut horizontal = Stripe::new(WHITE, blue);
+        horizontal
+            .set_transform(Transforms::rotation_y(PI / 2.0) * Transforms::scaling(0.25, 1.0, 1.0));
+        let pattern = Checker::nested(vertical, horizontal);
+
+        // the first square is striped along x, the next one along z
+        assert_eq!(pattern.local_pattern_at(Point::new(0.1, 0.0, 0.1)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.3, 0.0, 0.1)), red);
+        assert_eq!(pattern.local_pattern_at(Point::new(1.1, 0.0, 0.3)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(1.1, 0.0, 0.1)), blue);
+    }
+}
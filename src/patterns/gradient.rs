@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::core::{color::Color, point::Point};
+
+use super::{
+    pattern::{Pattern, PatternProps},
+    solid::Solid,
+};
+
+/// Blends linearly from one color or pattern to another along x, starting
+/// over at every unit.
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    props: PatternProps,
+    pub a: Arc<dyn Pattern>,
+    pub b: Arc<dyn Pattern>,
+}
+
+impl Gradient {
+    pub fn new(a: Color, b: Color) -> Self {
+        Gradient::nested(Solid::new(a), Solid::new(b))
+    }
+
+    /// Blends between two other patterns instead of two colors.
+    pub fn nested(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Self {
+        Gradient {
+            props: PatternProps::new(),
+            a: Arc::new(a),
+            b: Arc::new(b),
+        }
+    }
+}
+
+impl PartialEq for Gradient {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props && *self.a == *other.a && *self.b == *other.b
+    }
+}
+
+impl Pattern for Gradient {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let fraction = point.x - point.x.floor();
+
+        let a = self.a.pattern_at(point);
+        let b = self.b.pattern_at(point);
+
+        a + (b - a) * fraction
+    }
+}
+
+#[cfg(test)]
+mod gradient_tests {
+    use super::*;
+
+    #[test]
+    fn gradient_linearly_interpolates_between_colors() {
+        let pattern = Gradient::new(Color::WHITE, Color::BLACK);
+
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)),
+            Color::WHITE
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.25, 0.0, 0.0)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.5, 0.0, 0.0)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.75, 0.0, 0.0)),
+            Color::new(0.25, 0.25, 0.25)
+        );
+    }
+
+    #[test]
+    fn gradient_repeats_every_unit() {
+        let pattern = Gradient::new(Color::WHITE, Color::BLACK);
+
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(1.25, 0.0, 0.0)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(-0.75, 0.0, 0.0)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+    }
+}
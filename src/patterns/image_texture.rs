@@ -0,0 +1,246 @@
+use std::{path::Path, sync::Arc};
+
+use crate::{
+    core::{canvas::Canvas, color::Color},
+    io::image::{check_not_empty, load_image},
+};
+
+use super::uv_pattern::UvPattern;
+
+/// How a texture is sampled between the centers of its pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureFilter {
+    /// The color of the closest pixel, blocky when magnified.
+    Nearest,
+    /// A weighted average of the four closest pixels.
+    Bilinear,
+}
+
+/// What a texture shows outside the 0 to 1 range of texture coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureAddress {
+    /// The texture repeats.
+    Wrap,
+    /// The pixels on the edges stretch out forever.
+    Clamp,
+    /// The texture repeats, flipped every other time so the edges meet.
+    Mirror,
+}
+
+impl TextureAddress {
+    /// Maps a pixel index, possibly outside the texture, to one inside a
+    /// texture `size` pixels wide.
+    fn resolve(&self, index: i64, size: usize) -> usize {
+        let size = size as i64;
+
+        let resolved = match self {
+            TextureAddress::Wrap => index.rem_euclid(size),
+            TextureAddress::Clamp => index.clamp(0, size - 1),
+            TextureAddress::Mirror => {
+                let m = index.rem_euclid(2 * size);
+
+                if m < size {
+                    m
+                } else {
+                    2 * size - 1 - m
+                }
+            }
+        };
+
+        resolved as usize
+    }
+}
+
+/// A picture, such as a decal or a photo, used as a UV pattern.
+///
+/// `v` goes up the picture, so `(0, 0)` is its bottom left corner even though
+/// canvases are stored top row first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageTexture {
+    /// Shared, so large pictures aren't copied with the materials using them.
+    canvas: Arc<Canvas>,
+    pub filter: TextureFilter,
+    pub address: TextureAddress,
+}
+
+impl ImageTexture {
+    /// # Panics
+    ///
+    /// If the canvas has no pixels, as there would be nothing to sample.
+    pub fn new(canvas: Canvas, filter: TextureFilter, address: TextureAddress) -> Self {
+        assert!(
+            canvas.width() > 0 && canvas.height() > 0,
+            "texture canvas must not be empty, got {}x{}",
+            canvas.width(),
+            canvas.height()
+        );
+
+        ImageTexture {
+            canvas: Arc::new(canvas),
+            filter,
+            address,
+        }
+    }
+
+    /// Reads a texture from a PNG, JPEG or PPM file. Fails if the file
+    /// can't be decoded or holds an empty image.
+    pub fn load(
+        path: impl AsRef<Path>,
+        filter: TextureFilter,
+        address: TextureAddress,
+    ) -> image::ImageResult<Self> {
+        let canvas = load_image(path)?;
+        check_not_empty(&canvas)?;
+
+        Ok(ImageTexture::new(canvas, filter, address))
+    }
+
+    pub fn canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+
+    fn texel(&self, x: i64, y: i64) -> Color {
+        let x = self.address.resolve(x, self.canvas.width());
+        let y = self.address.resolve(y, self.canvas.height());
+
+        self.canvas[[x, y]]
+    }
+}
+
+impl UvPattern for ImageTexture {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color {
+        // position in pixels, with pixel centers at half units
+        let x = u * self.canvas.width() as f64;
+        let y = (1.0 - v) * self.canvas.height() as f64;
+
+        match self.filter {
+            TextureFilter::Nearest => self.texel(x.floor() as i64, y.floor() as i64),
+            TextureFilter::Bilinear => {
+                let (x, y) = (x - 0.5, y - 0.5);
+                let (x0, y0) = (x.floor(), y.floor());
+                let (tx, ty) = (x - x0, y - y0);
+                let (x0, y0) = (x0 as i64, y0 as i64);
+
+                let top = self.texel(x0, y0) * (1.0 - tx) + self.texel(x0 + 1, y0) * tx;
+                let bottom = self.texel(x0, y0 + 1) * (1.0 - tx) + self.texel(x0 + 1, y0 + 1) * tx;
+
+                top * (1.0 - ty) + bottom * ty
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod image_texture_tests {
+    use crate::{
+        core::point::Point,
+        io::image::make_image,
+        patterns::{pattern::Pattern, texture_map::TextureMap, uv_map::UvMap},
+        utils::utils::temp_path,
+    };
+
+    use super::*;
+
+    /// A 2x2 texture: black and white on the top row, red and blue below.
+    fn quad() -> Canvas {
+        let mut canvas = Canvas::new(2, 2);
+        canvas[[1, 0]] = Color::WHITE;
+        canvas[[0, 1]] = Color::new(1.0, 0.0, 0.0);
+        canvas[[1, 1]] = Color::new(0.0, 0.0, 1.0);
+
+        canvas
+    }
+
+    #[test]
+    fn nearest_filter_picks_pixel_under_coordinates() {
+        let texture = ImageTexture::new(quad(), TextureFilter::Nearest, TextureAddress::Wrap);
+        let cases = [
+            (0.25, 0.75, Color::BLACK),
+            (0.75, 0.75, Color::WHITE),
+            (0.25, 0.25, Color::new(1.0, 0.0, 0.0)),
+            (0.75, 0.25, Color::new(0.0, 0.0, 1.0)),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(texture.uv_pattern_at(u, v), expected);
+        }
+    }
+
+    #[test]
+    fn bilinear_filter_blends_neighbouring_pixels() {
+        let texture = ImageTexture::new(quad(), TextureFilter::Bilinear, TextureAddress::Clamp);
+
+        // on a pixel center the pixel itself comes back
+        assert_eq!(texture.uv_pattern_at(0.75, 0.75), Color::WHITE);
+        // halfway between the centers of the two top pixels
+        assert_eq!(texture.uv_pattern_at(0.5, 0.75), Color::new(0.5, 0.5, 0.5));
+        // the middle of the texture averages all four
+        assert_eq!(texture.uv_pattern_at(0.5, 0.5), Color::new(0.5, 0.25, 0.5));
+    }
+
+    #[test]
+    fn wrap_addressing_repeats_texture() {
+        let texture = ImageTexture::new(quad(), TextureFilter::Nearest, TextureAddress::Wrap);
+
+        assert_eq!(texture.uv_pattern_at(1.25, 0.75), Color::BLACK);
+        assert_eq!(texture.uv_pattern_at(-0.25, 0.75), Color::WHITE);
+        assert_eq!(texture.uv_pattern_at(0.25, 1.25), Color::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn clamp_addressing_stretches_edges() {
+        let texture = ImageTexture::new(quad(), TextureFilter::Nearest, TextureAddress::Clamp);
+
+        assert_eq!(texture.uv_pattern_at(1.25, 0.75), Color::WHITE);
+        assert_eq!(texture.uv_pattern_at(-3.0, 0.75), Color::BLACK);
+        assert_eq!(texture.uv_pattern_at(0.25, 5.0), Color::BLACK);
+    }
+
+    #[test]
+    fn mirror_addressing_flips_every_other_repeat() {
+        let texture = ImageTexture::new(quad(), TextureFilter::Nearest, TextureAddress::Mirror);
+
+        assert_eq!(texture.uv_pattern_at(1.25, 0.75), Color::WHITE);
+        assert_eq!(texture.uv_pattern_at(1.75, 0.75), Color::BLACK);
+        assert_eq!(texture.uv_pattern_at(2.25, 0.75), Color::BLACK);
+        assert_eq!(texture.uv_pattern_at(-0.25, 0.75), Color::BLACK);
+    }
+
+    #[test]
+    fn image_texture_on_plane() {
+        let texture = ImageTexture::new(quad(), TextureFilter::Nearest, TextureAddress::Wrap);
+        let pattern = TextureMap::new(texture, UvMap::Planar);
+
+        // planes map z to v, so the top of the picture is towards +z
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.75, 0.0, 0.75)),
+            Color::WHITE
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(2.75, 0.0, 0.25)),
+            Color::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "texture canvas must not be empty, got 0x4")]
+    fn creating_texture_from_empty_canvas_panics() {
+        ImageTexture::new(
+            Canvas::new(0, 4),
+            TextureFilter::Nearest,
+            TextureAddress::Wrap,
+        );
+    }
+
+    #[test]
+    fn loading_texture_from_file() {
+        let path = temp_path("loading_texture_from_file.png");
+        make_image(&quad()).save(&path).unwrap();
+
+        let texture =
+            ImageTexture::load(&path, TextureFilter::Nearest, TextureAddress::Wrap).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(*texture.canvas(), quad());
+    }
+}
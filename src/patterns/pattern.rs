@@ -0,0 +1,194 @@
+use std::fmt::Debug;
+
+use crate::{
+    core::{color::Color, matrix4::Matrix4, point::Point},
+    shapes::shape::Shape,
+    utils::utils::DynEq,
+};
+
+/// State shared by every pattern: its transform, layered on top of the
+/// transform of the object it is applied to, and the cached inverse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternProps {
+    transform: Matrix4,
+    inverse: Matrix4,
+}
+
+impl PatternProps {
+    pub fn new() -> Self {
+        PatternProps {
+            transform: Matrix4::IDENTITY,
+            inverse: Matrix4::IDENTITY,
+        }
+    }
+
+    pub fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.inverse = transform.inverse();
+    }
+}
+
+impl Default for PatternProps {
+    fn default() -> Self {
+        PatternProps::new()
+    }
+}
+
+/// A color that varies over space, used in place of a flat material color.
+///
+/// Implementors only compute colors in pattern space through
+/// `local_pattern_at`; getting there from world space, through the object
+/// and the pattern transforms, is handled here.
+pub trait Pattern: Debug + Send + Sync + DynEq {
+    fn props(&self) -> &PatternProps;
+
+    fn props_mut(&mut self) -> &mut PatternProps;
+
+    /// Color of the pattern at a point in pattern space.
+    fn local_pattern_at(&self, point: Point) -> Color;
+
+    fn transform(&self) -> &Matrix4 {
+        self.props().transform()
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.props_mut().set_transform(transform);
+    }
+
+    /// Color at a point in the space the pattern is placed in, usually the
+    /// object space of the shape it is applied to.
+    fn pattern_at(&self, point: Point) -> Color {
+        self.local_pattern_at(*self.props().inverse() * point)
+    }
+
+    /// Color at a world-space point on `object`.
+    fn pattern_at_shape(&self, object: &dyn Shape, world_point: Point) -> Color {
+        self.pattern_at(object.world_to_object(world_point))
+    }
+}
+
+impl PartialEq for dyn Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_any())
+    }
+}
+
+#[cfg(test)]
+pub mod pattern_tests {
+    use crate::{
+        core::transforms::Transforms,
+        patterns::solid::Solid,
+        shapes::{group::Group, sphere::Sphere},
+    };
+
+    use super::*;
+
+    /// A pattern whose color is the point it was looked up at.
+    #[derive(Debug, Default, PartialEq)]
+    pub struct TestPattern {
+        props: PatternProps,
+    }
+
+    impl Pattern for TestPattern {
+        fn props(&self) -> &PatternProps {
+            &self.props
+        }
+
+        fn props_mut(&mut self) -> &mut PatternProps {
+            &mut self.props
+        }
+
+        fn local_pattern_at(&self, point: Point) -> Color {
+            Color::new(point.x, point.y, point.z)
+        }
+    }
+
+    #[test]
+    fn default_pattern_transformation() {
+        let pattern = TestPattern::default();
+
+        assert_eq!(*pattern.transform(), Matrix4::IDENTITY);
+    }
+
+    #[test]
+    fn assigning_transformation() {
+        let mut pattern = TestPattern::default();
+
+        pattern.set_transform(Transforms::translation(1.0, 2.0, 3.0));
+
+        assert_eq!(*pattern.transform(), Transforms::translation(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn pattern_with_object_transformation() {
+        let mut shape = Sphere::new();
+        shape.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+        let pattern = TestPattern::default();
+
+        let actual = pattern.pattern_at_shape(&shape, Point::new(2.0, 3.0, 4.0));
+        let expected = Color::new(1.0, 1.5, 2.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn pattern_with_pattern_transformation() {
+        let shape = Sphere::new();
+        let mut pattern = TestPattern::default();
+        pattern.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+
+        let actual = pattern.pattern_at_shape(&shape, Point::new(2.0, 3.0, 4.0));
+        let expected = Color::new(1.0, 1.5, 2.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn pattern_with_both_object_and_pattern_transformation() {
+        let mut shape = Sphere::new();
+        shape.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+        let mut pattern = TestPattern::default();
+        pattern.set_transform(Transforms::translation(0.5, 1.0, 1.5));
+
+        let actual = pattern.pattern_at_shape(&shape, Point::new(2.5, 3.0, 3.5));
+        let expected = Color::new(0.75, 0.5, 0.25);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn pattern_on_shape_nested_in_group() {
+        let mut group = Group::new();
+        group.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+        let mut shape = Sphere::new();
+        shape.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+        group.add_child(shape);
+        let shape = group.children()[0].as_ref();
+        let pattern = TestPattern::default();
+
+        let actual = pattern.pattern_at_shape(shape, Point::new(12.0, 2.0, 4.0));
+        let expected = Color::new(1.0, 1.0, 2.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn comparing_patterns_behind_trait_objects() {
+        let white: &dyn Pattern = &Solid::new(Color::WHITE);
+        let also_white: &dyn Pattern = &Solid::new(Color::WHITE);
+        let black: &dyn Pattern = &Solid::new(Color::BLACK);
+        let test: &dyn Pattern = &TestPattern::default();
+
+        assert!(white == also_white);
+        assert!(white != black);
+        assert!(white != test);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{core::point::Point, utils::utils::splitmix64};
+
+/// Seed used when none is given, so renders are the same on every run.
+pub const DEFAULT_SEED: u64 = 0;
+
+/// Ken Perlin's improved gradient noise in three dimensions.
+///
+/// The gradients are picked through a permutation table shuffled from a
+/// seed, so the same seed always produces the same noise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Perlin {
+    seed: u64,
+    permutation: Vec<u8>,
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        let mut table: Vec<u8> = (0..=255).collect();
+        let mut state = seed;
+
+        // Fisher-Yates shuffle driven by splitmix64
+        for i in (1..table.len()).rev() {
+            let z = splitmix64(&mut state);
+
+            table.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+
+        // doubled so lookups never need to wrap around
+        let permutation = table.iter().chain(table.iter()).copied().collect();
+
+        Perlin { seed, permutation }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Noise at `point`, roughly between -1 and 1. It varies smoothly through
+    /// space and is always 0 at points with integer coordinates.
+    pub fn noise(&self, point: Point) -> f64 {
+        let (xi, x) = split(point.x);
+        let (yi, y) = split(point.y);
+        let (zi, z) = split(point.z);
+
+        let u = fade(x);
+        let v = fade(y);
+        let w = fade(z);
+
+        let p = |i: usize| self.permutation[i] as usize;
+
+        let a = p(xi) + yi;
+        let aa = p(a) + zi;
+        let ab = p(a + 1) + zi;
+        let b = p(xi + 1) + yi;
+        let ba = p(b) + zi;
+        let bb = p(b + 1) + zi;
+
+        lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad(p(aa), x, y, z), grad(p(ba), x - 1.0, y, z)),
+                lerp(
+                    u,
+                    grad(p(ab), x, y - 1.0, z),
+                    grad(p(bb), x - 1.0, y - 1.0, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad(p(aa + 1), x, y, z - 1.0),
+                    grad(p(ba + 1), x - 1.0, y, z - 1.0),
+                ),
+                lerp(
+                    u,
+                    grad(p(ab + 1), x, y - 1.0, z - 1.0),
+                    grad(p(bb + 1), x - 1.0, y - 1.0, z - 1.0),
+                ),
+            ),
+        )
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Perlin::new(DEFAULT_SEED)
+    }
+}
+
+/// Splits a coordinate into its lattice cell, wrapped to the table size, and
+/// the position inside the cell.
+fn split(coordinate: f64) -> (usize, f64) {
+    let floor = coordinate.floor();
+
+    ((floor as i64 & 255) as usize, coordinate - floor)
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// Dot product of the offset with one of twelve gradient directions picked
+/// by `hash`.
+fn grad(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+#[cfg(test)]
+mod perlin_tests {
+    use float_cmp::assert_approx_eq;
+
+    use super::*;
+
+    fn sample_points() -> impl Iterator<Item = Point> {
+        (0..500).map(|i| {
+            let t = i as f64;
+            Point::new(t * 0.37 - 50.0, t * 0.113, -t * 0.71 + 3.3)
+        })
+    }
+
+    #[test]
+    fn same_seed_gives_same_noise() {
+        let a = Perlin::new(42);
+        let b = Perlin::new(42);
+
+        assert_eq!(a, b);
+
+        for point in sample_points() {
+            assert_eq!(a.noise(point), b.noise(point));
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_noise() {
+        let a = Perlin::new(1);
+        let b = Perlin::new(2);
+
+        assert!(sample_points().any(|point| a.noise(point) != b.noise(point)));
+    }
+
+    #[test]
+    fn noise_is_zero_on_lattice_points() {
+        let perlin = Perlin::default();
+
+        for (x, y, z) in [(0.0, 0.0, 0.0), (1.0, 2.0, 3.0), (-4.0, 7.0, -300.0)] {
+            assert_approx_eq!(f64, perlin.noise(Point::new(x, y, z)), 0.0);
+        }
+    }
+
+    #[test]
+    fn noise_stays_within_unit_range() {
+        let perlin = Perlin::default();
+
+        for point in sample_points() {
+            let n = perlin.noise(point);
+
+            assert!((-1.0..=1.0).contains(&n), "{n} out of range");
+        }
+    }
+
+    #[test]
+    fn noise_varies_smoothly() {
+        let perlin = Perlin::default();
+
+        for point in sample_points() {
+            let nearby = Point::new(point.x + 1e-4, point.y, point.z);
+
+            assert!((perlin.noise(point) - perlin.noise(nearby)).abs() < 1e-3);
+        }
+    }
+}
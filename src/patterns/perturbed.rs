@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use crate::core::{color::Color, point::Point, vector::Vector};
+
+use super::{
+    pattern::{Pattern, PatternProps},
+    perlin::Perlin,
+};
+
+/// How far, at most, lookups are pushed by default.
+pub const DEFAULT_SCALE: f64 = 0.2;
+
+/// Another pattern with its lookup points jittered by Perlin noise, which
+/// turns straight stripes and rings into marble or wood grain.
+#[derive(Debug, Clone)]
+pub struct Perturbed {
+    props: PatternProps,
+    pub pattern: Arc<dyn Pattern>,
+    pub noise: Perlin,
+    /// Largest distance a point is moved along each axis.
+    pub scale: f64,
+}
+
+impl Perturbed {
+    pub fn new(pattern: impl Pattern + 'static) -> Self {
+        Perturbed {
+            props: PatternProps::new(),
+            pattern: Arc::new(pattern),
+            noise: Perlin::default(),
+            scale: DEFAULT_SCALE,
+        }
+    }
+}
+
+impl PartialEq for Perturbed {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props
+            && *self.pattern == *other.pattern
+            && self.noise == other.noise
+            && self.scale == other.scale
+    }
+}
+
+impl Pattern for Perturbed {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        // the noise is sampled at shifted points so each axis gets its own
+        let jitter = Vector::new(
+            self.noise.noise(point),
+            self.noise.noise(point + Vector::new(5.2, 1.3, 7.1)),
+            self.noise.noise(point + Vector::new(9.7, 3.4, 2.8)),
+        );
+
+        self.pattern.pattern_at(point + jitter * self.scale)
+    }
+}
+
+#[cfg(test)]
+mod perturbed_tests {
+    use crate::patterns::{pattern::pattern_tests::TestPattern, stripe::Stripe};
+
+    use super::*;
+
+    fn sample_points() -> impl Iterator<Item = Point> {
+        (0..100).map(|i| {
+            let t = i as f64;
+            Point::new(t * 0.37, t * 0.21 - 4.0, t * 0.13)
+        })
+    }
+
+    #[test]
+    fn unscaled_perturbation_leaves_pattern_unchanged() {
+        let mut pattern = Perturbed::new(TestPattern::default());
+        pattern.scale = 0.0;
+
+        for point in sample_points() {
+            assert_eq!(
+                pattern.local_pattern_at(point),
+                Color::new(point.x, point.y, point.z)
+            );
+        }
+    }
+
+    #[test]
+    fn perturbation_moves_lookups_at_most_by_scale() {
+        let pattern = Perturbed::new(TestPattern::default());
+
+        for point in sample_points() {
+            let c = pattern.local_pattern_at(point);
+
+            assert!((c.red - point.x).abs() <= pattern.scale);
+            assert!((c.green - point.y).abs() <= pattern.scale);
+            assert!((c.blue - point.z).abs() <= pattern.scale);
+        }
+
+        assert!(sample_points().any(|point| {
+            pattern.local_pattern_at(point) != Color::new(point.x, point.y, point.z)
+        }));
+    }
+
+    #[test]
+    fn perturbation_is_deterministic_for_a_seed() {
+        let stripes = Stripe::new(Color::WHITE, Color::BLACK);
+        let mut a = Perturbed::new(stripes.clone());
+        a.noise = Perlin::new(7);
+        let mut b = Perturbed::new(stripes);
+        b.noise = Perlin::new(7);
+
+        assert_eq!(a, b);
+
+        for point in sample_points() {
+            assert_eq!(a.local_pattern_at(point), b.local_pattern_at(point));
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use crate::core::{color::Color, point::Point};
+
+use super::{
+    pattern::{Pattern, PatternProps},
+    solid::Solid,
+};
+
+/// Concentric rings around the y axis, alternating between two colors or
+/// patterns every unit of distance.
+#[derive(Debug, Clone)]
+pub struct Ring {
+    props: PatternProps,
+    pub a: Arc<dyn Pattern>,
+    pub b: Arc<dyn Pattern>,
+}
+
+impl Ring {
+    pub fn new(a: Color, b: Color) -> Self {
+        Ring::nested(Solid::new(a), Solid::new(b))
+    }
+
+    /// Fills alternate rings with two other patterns.
+    pub fn nested(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Self {
+        Ring {
+            props: PatternProps::new(),
+            a: Arc::new(a),
+            b: Arc::new(b),
+        }
+    }
+}
+
+impl PartialEq for Ring {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props && *self.a == *other.a && *self.b == *other.b
+    }
+}
+
+impl Pattern for Ring {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let distance = (point.x.powi(2) + point.z.powi(2)).sqrt();
+
+        if distance.floor().rem_euclid(2.0) == 0.0 {
+            self.a.pattern_at(point)
+        } else {
+            self.b.pattern_at(point)
+        }
+    }
+}
+
+#[cfg(test)]
+mod ring_tests {
+    use super::*;
+
+    #[test]
+    fn ring_extends_in_both_x_and_z() {
+        let pattern = Ring::new(Color::WHITE, Color::BLACK);
+
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)),
+            Color::WHITE
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(1.0, 0.0, 0.0)),
+            Color::BLACK
+        );
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.0, 0.0, 1.0)),
+            Color::BLACK
+        );
+        // 0.708 is just slightly more than sqrt(2) / 2
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(0.708, 0.0, 0.708)),
+            Color::BLACK
+        );
+    }
+}
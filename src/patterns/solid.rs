@@ -0,0 +1,51 @@
+use crate::core::{color::Color, point::Point};
+
+use super::pattern::{Pattern, PatternProps};
+
+/// The same color everywhere, what a flat material color used to be.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solid {
+    props: PatternProps,
+    pub color: Color,
+}
+
+impl Solid {
+    pub fn new(color: Color) -> Self {
+        Solid {
+            props: PatternProps::new(),
+            color,
+        }
+    }
+}
+
+impl Pattern for Solid {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, _point: Point) -> Color {
+        self.color
+    }
+}
+
+#[cfg(test)]
+mod solid_tests {
+    use super::*;
+
+    #[test]
+    fn solid_pattern_is_constant_everywhere() {
+        let pattern = Solid::new(Color::new(0.2, 0.4, 0.6));
+
+        for point in [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.5, -2.0, 7.0),
+            Point::new(-100.0, 0.3, 0.9),
+        ] {
+            assert_eq!(pattern.local_pattern_at(point), Color::new(0.2, 0.4, 0.6));
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use crate::core::{color::Color, point::Point};
+
+use super::{
+    pattern::{Pattern, PatternProps},
+    solid::Solid,
+};
+
+/// Stripes alternating between two colors or patterns every unit along x.
+#[derive(Debug, Clone)]
+pub struct Stripe {
+    props: PatternProps,
+    pub a: Arc<dyn Pattern>,
+    pub b: Arc<dyn Pattern>,
+}
+
+impl Stripe {
+    pub fn new(a: Color, b: Color) -> Self {
+        Stripe::nested(Solid::new(a), Solid::new(b))
+    }
+
+    /// Uses two other patterns in place of the colors. Their transforms are
+    /// relative to this pattern.
+    pub fn nested(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Self {
+        Stripe {
+            props: PatternProps::new(),
+            a: Arc::new(a),
+            b: Arc::new(b),
+        }
+    }
+}
+
+impl PartialEq for Stripe {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props && *self.a == *other.a && *self.b == *other.b
+    }
+}
+
+impl Pattern for Stripe {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        if point.x.floor().rem_euclid(2.0) == 0.0 {
+            self.a.pattern_at(point)
+        } else {
+            self.b.pattern_at(point)
+        }
+    }
+}
+
+#[cfg(test)]
+mod stripe_tests {
+    use crate::{
+        core::transforms::Transforms,
+        shapes::{shape::Shape, sphere::Sphere},
+    };
+
+    use super::*;
+
+    const WHITE: Color = Color::WHITE;
+    const BLACK: Color = Color::BLACK;
+
+    #[test]
+    fn creating_stripe_pattern() {
+        let pattern = Stripe::new(WHITE, BLACK);
+
+        let (white, black): (&dyn Pattern, &dyn Pattern) = (&Solid::new(WHITE), &Solid::new(BLACK));
+
+        assert!(*pattern.a == *white);
+        assert!(*pattern.b == *black);
+    }
+
+    #[test]
+    fn stripe_pattern_is_constant_in_y() {
+        let pattern = Stripe::new(WHITE, BLACK);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 1.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 2.0, 0.0)), WHITE);
+    }
+
+    #[test]
+    fn stripe_pattern_is_constant_in_z() {
+        let pattern = Stripe::new(WHITE, BLACK);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 1.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 2.0)), WHITE);
+    }
+
+    #[test]
+    fn stripe_pattern_alternates_in_x() {
+        let pattern = Stripe::new(WHITE, BLACK);
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.0, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.9, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(1.0, 0.0, 0.0)), BLACK);
+        assert_eq!(pattern.local_pattern_at(Point::new(-0.1, 0.0, 0.0)), BLACK);
+        assert_eq!(pattern.local_pattern_at(Point::new(-1.0, 0.0, 0.0)), BLACK);
+        assert_eq!(pattern.local_pattern_at(Point::new(-1.1, 0.0, 0.0)), WHITE);
+    }
+
+    #[test]
+    fn stripes_with_object_transformation() {
+        let mut object = Sphere::new();
+        object.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+        let pattern = Stripe::new(WHITE, BLACK);
+
+        let actual = pattern.pattern_at_shape(&object, Point::new(1.5, 0.0, 0.0));
+
+        assert_eq!(actual, WHITE);
+    }
+
+    #[test]
+    fn stripes_with_pattern_transformation() {
+        let object = Sphere::new();
+        let mut pattern = Stripe::new(WHITE, BLACK);
+        pattern.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+
+        let actual = pattern.pattern_at_shape(&object, Point::new(1.5, 0.0, 0.0));
+
+        assert_eq!(actual, WHITE);
+    }
+
+    #[test]
+    fn stripes_with_both_object_and_pattern_transformation() {
+        let mut object = Sphere::new();
+        object.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+        let mut pattern = Stripe::new(WHITE, BLACK);
+        pattern.set_transform(Transforms::translation(0.5, 0.0, 0.0));
+
+        let actual = pattern.pattern_at_shape(&object, Point::new(2.5, 0.0, 0.0));
+
+        assert_eq!(actual, WHITE);
+    }
+
+    #[test]
+    fn nested_patterns_apply_their_own_transform() {
+        let mut inner = Stripe::new(WHITE, BLACK);
+        inner.set_transform(Transforms::scaling(0.5, 1.0, 1.0));
+        let pattern = Stripe::nested(inner, Solid::new(Color::new(1.0, 0.0, 0.0)));
+
+        assert_eq!(pattern.local_pattern_at(Point::new(0.25, 0.0, 0.0)), WHITE);
+        assert_eq!(pattern.local_pattern_at(Point::new(0.75, 0.0, 0.0)), BLACK);
+        assert_eq!(
+            pattern.local_pattern_at(Point::new(1.25, 0.0, 0.0)),
+            Color::new(1.0, 0.0, 0.0)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use crate::core::{color::Color, point::Point};
+
+use super::{
+    pattern::{Pattern, PatternProps},
+    uv_map::{cube_map, CubeFace, UvMap},
+    uv_pattern::UvPattern,
+};
+
+/// Wraps a flat UV pattern around a shape using one of the `UvMap`
+/// projections.
+#[derive(Debug, Clone)]
+pub struct TextureMap {
+    props: PatternProps,
+    pub uv_pattern: Arc<dyn UvPattern>,
+    pub map: UvMap,
+}
+
+impl TextureMap {
+    pub fn new(uv_pattern: impl UvPattern + 'static, map: UvMap) -> Self {
+        TextureMap {
+            props: PatternProps::new(),
+            uv_pattern: Arc::new(uv_pattern),
+            map,
+        }
+    }
+}
+
+impl PartialEq for TextureMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props && *self.uv_pattern == *other.uv_pattern && self.map == other.map
+    }
+}
+
+impl Pattern for TextureMap {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let (u, v) = self.map.map(point);
+
+        self.uv_pattern.uv_pattern_at(u, v)
+    }
+}
+
+/// Wraps a different UV pattern around each face of a cube.
+#[derive(Debug, Clone)]
+pub struct CubeMap {
+    props: PatternProps,
+    pub left: Arc<dyn UvPattern>,
+    pub front: Arc<dyn UvPattern>,
+    pub right: Arc<dyn UvPattern>,
+    pub back: Arc<dyn UvPattern>,
+    pub up: Arc<dyn UvPattern>,
+    pub down: Arc<dyn UvPattern>,
+}
+
+impl CubeMap {
+    pub fn new(
+        left: impl UvPattern + 'static,
+        front: impl UvPattern + 'static,
+        right: impl UvPattern + 'static,
+        back: impl UvPattern + 'static,
+        up: impl UvPattern + 'static,
+        down: impl UvPattern + 'static,
+    ) -> Self {
+        CubeMap {
+            props: PatternProps::new(),
+            left: Arc::new(left),
+            front: Arc::new(front),
+            right: Arc::new(right),
+            back: Arc::new(back),
+            up: Arc::new(up),
+            down: Arc::new(down),
+        }
+    }
+
+    pub fn face(&self, face: CubeFace) -> &dyn UvPattern {
+        match face {
+            CubeFace::Left => self.left.as_ref(),
+            CubeFace::Front => self.front.as_ref(),
+            CubeFace::Right => self.right.as_ref(),
+            CubeFace::Back => self.back.as_ref(),
+            CubeFace::Up => self.up.as_ref(),
+            CubeFace::Down => self.down.as_ref(),
+        }
+    }
+}
+
+impl PartialEq for CubeMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.props == other.props
+            && *self.left == *other.left
+            && *self.front == *other.front
+            && *self.right == *other.right
+            && *self.back == *other.back
+            && *self.up == *other.up
+            && *self.down == *other.down
+    }
+}
+
+impl Pattern for CubeMap {
+    fn props(&self) -> &PatternProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut PatternProps {
+        &mut self.props
+    }
+
+    fn local_pattern_at(&self, point: Point) -> Color {
+        let (face, u, v) = cube_map(point);
+
+        self.face(face).uv_pattern_at(u, v)
+    }
+}
+
+#[cfg(test)]
+mod texture_map_tests {
+    use crate::patterns::uv_pattern::{UvAlignCheck, UvCheckers};
+
+    use super::*;
+
+    #[test]
+    fn using_texture_map_pattern_with_spherical_map() {
+        let checkers = UvCheckers::new(16.0, 8.0, Color::BLACK, Color::WHITE);
+        let pattern = TextureMap::new(checkers, UvMap::Spherical);
+        let cases = [
+            (Point::new(0.4315, 0.4670, 0.7719), Color::WHITE),
+            (Point::new(-0.9654, 0.2552, -0.0534), Color::BLACK),
+            (Point::new(0.1039, 0.7090, 0.6975), Color::WHITE),
+            (Point::new(-0.4986, -0.7856, -0.3663), Color::BLACK),
+            (Point::new(-0.0317, -0.9395, 0.3411), Color::BLACK),
+            (Point::new(0.4809, -0.7721, 0.4154), Color::BLACK),
+            (Point::new(0.0285, -0.9612, -0.2745), Color::BLACK),
+            (Point::new(-0.5734, -0.2162, -0.7903), Color::WHITE),
+            (Point::new(0.7688, -0.1470, 0.6223), Color::BLACK),
+            (Point::new(-0.7652, 0.2175, 0.6060), Color::BLACK),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(pattern.local_pattern_at(point), expected);
+        }
+    }
+
+    #[test]
+    fn finding_colors_on_mapped_cube() {
+        let red = Color::new(1.0, 0.0, 0.0);
+        let yellow = Color::new(1.0, 1.0, 0.0);
+        let brown = Color::new(1.0, 0.5, 0.0);
+        let green = Color::new(0.0, 1.0, 0.0);
+        let cyan = Color::new(0.0, 1.0, 1.0);
+        let blue = Color::new(0.0, 0.0, 1.0);
+        let purple = Color::new(1.0, 0.0, 1.0);
+        let white = Color::WHITE;
+        let pattern = CubeMap::new(
+            UvAlignCheck::new(yellow, cyan, red, blue, brown),
+            UvAlignCheck::new(cyan, red, yellow, brown, green),
+            UvAlignCheck::new(red, yellow, purple, green, white),
+            UvAlignCheck::new(green, purple, cyan, white, blue),
+            UvAlignCheck::new(brown, cyan, purple, red, yellow),
+            UvAlignCheck::new(purple, brown, green, blue, white),
+        );
+        let cases = [
+            // left
+            (Point::new(-1.0, 0.0, 0.0), yellow),
+            (Point::new(-1.0, 0.9, -0.9), cyan),
+            (Point::new(-1.0, 0.9, 0.9), red),
+            (Point::new(-1.0, -0.9, -0.9), blue),
+            (Point::new(-1.0, -0.9, 0.9), brown),
+            // front
+            (Point::new(0.0, 0.0, 1.0), cyan),
+            (Point::new(-0.9, 0.9, 1.0), red),
+            (Point::new(0.9, 0.9, 1.0), yellow),
+            (Point::new(-0.9, -0.9, 1.0), brown),
+            (Point::new(0.9, -0.9, 1.0), green),
+            // right
+            (Point::new(1.0, 0.0, 0.0), red),
+            (Point::new(1.0, 0.9, 0.9), yellow),
+            (Point::new(1.0, 0.9, -0.9), purple),
+            (Point::new(1.0, -0.9, 0.9), green),
+            (Point::new(1.0, -0.9, -0.9), white),
+            // back
+            (Point::new(0.0, 0.0, -1.0), green),
+            (Point::new(0.9, 0.9, -1.0), purple),
+            (Point::new(-0.9, 0.9, -1.0), cyan),
+            (Point::new(0.9, -0.9, -1.0), white),
+            (Point::new(-0.9, -0.9, -1.0), blue),
+            // up
+            (Point::new(0.0, 1.0, 0.0), brown),
+            (Point::new(-0.9, 1.0, -0.9), cyan),
+            (Point::new(0.9, 1.0, -0.9), purple),
+            (Point::new(-0.9, 1.0, 0.9), red),
+            (Point::new(0.9, 1.0, 0.9), yellow),
+            // down
+            (Point::new(0.0, -1.0, 0.0), purple),
+            (Point::new(-0.9, -1.0, 0.9), brown),
+            (Point::new(0.9, -1.0, 0.9), green),
+            (Point::new(-0.9, -1.0, -0.9), blue),
+            (Point::new(0.9, -1.0, -0.9), white),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(pattern.local_pattern_at(point), expected, "at {point:?}");
+        }
+    }
+
+    #[test]
+    fn texture_maps_compare_pattern_and_projection() {
+        let checkers = UvCheckers::new(2.0, 2.0, Color::BLACK, Color::WHITE);
+
+        assert_eq!(
+            TextureMap::new(checkers.clone(), UvMap::Planar),
+            TextureMap::new(checkers.clone(), UvMap::Planar)
+        );
+        assert_ne!(
+            TextureMap::new(checkers.clone(), UvMap::Planar),
+            TextureMap::new(checkers, UvMap::Cylindrical)
+        );
+    }
+}
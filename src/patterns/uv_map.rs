@@ -0,0 +1,224 @@
+use std::f64::consts::PI;
+
+use crate::core::point::Point;
+
+/// How a point on the surface of a shape is flattened to texture
+/// coordinates, `u` across and `v` up, both between 0 and 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvMap {
+    /// Longitude and latitude on a unit sphere.
+    Spherical,
+    /// x and z, repeating every unit, for planes.
+    Planar,
+    /// Angle around the y axis and height, repeating every unit, for
+    /// cylinders.
+    Cylindrical,
+}
+
+impl UvMap {
+    pub fn map(&self, point: Point) -> (f64, f64) {
+        match self {
+            UvMap::Spherical => spherical_map(point),
+            UvMap::Planar => planar_map(point),
+            UvMap::Cylindrical => cylindrical_map(point),
+        }
+    }
+}
+
+/// `u` going once around the y axis, counterclockwise seen from above.
+fn azimuth(point: Point) -> f64 {
+    let theta = point.x.atan2(point.z);
+    let raw_u = theta / (2.0 * PI);
+
+    1.0 - (raw_u + 0.5)
+}
+
+pub fn spherical_map(point: Point) -> (f64, f64) {
+    let radius = (point.x.powi(2) + point.y.powi(2) + point.z.powi(2)).sqrt();
+    let phi = (point.y / radius).acos();
+
+    (azimuth(point), 1.0 - phi / PI)
+}
+
+pub fn planar_map(point: Point) -> (f64, f64) {
+    (point.x.rem_euclid(1.0), point.z.rem_euclid(1.0))
+}
+
+pub fn cylindrical_map(point: Point) -> (f64, f64) {
+    (azimuth(point), point.y.rem_euclid(1.0))
+}
+
+/// One of the six faces of the unit cube.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubeFace {
+    Left,
+    Right,
+    Front,
+    Back,
+    Up,
+    Down,
+}
+
+impl CubeFace {
+    /// The face of the cube a point on its surface lies on, decided by the
+    /// coordinate with the largest magnitude.
+    pub fn from_point(point: Point) -> CubeFace {
+        let coord = point.x.abs().max(point.y.abs()).max(point.z.abs());
+
+        if coord == point.x {
+            CubeFace::Right
+        } else if coord == -point.x {
+            CubeFace::Left
+        } else if coord == point.y {
+            CubeFace::Up
+        } else if coord == -point.y {
+            CubeFace::Down
+        } else if coord == point.z {
+            CubeFace::Front
+        } else {
+            CubeFace::Back
+        }
+    }
+}
+
+/// The face a point on the unit cube lies on, and its texture coordinates
+/// on that face. Every face is seen from outside the cube, with `v` going
+/// up for the side faces and towards the back for the top one.
+pub fn cube_map(point: Point) -> (CubeFace, f64, f64) {
+    let face = CubeFace::from_point(point);
+    let wrap = |x: f64| (x + 1.0).rem_euclid(2.0) / 2.0;
+
+    let (u, v) = match face {
+        CubeFace::Front => (wrap(point.x), wrap(point.y)),
+        CubeFace::Back => (wrap(-point.x), wrap(point.y)),
+        CubeFace::Left => (wrap(point.z), wrap(point.y)),
+        CubeFace::Right => (wrap(-point.z), wrap(point.y)),
+        CubeFace::Up => (wrap(point.x), wrap(-point.z)),
+        CubeFace::Down => (wrap(point.x), wrap(point.z)),
+    };
+
+    (face, u, v)
+}
+
+#[cfg(test)]
+mod uv_map_tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use float_cmp::assert_approx_eq;
+
+    use crate::utils::utils::EPSILON;
+
+    use super::*;
+
+    fn assert_uv(actual: (f64, f64), expected: (f64, f64)) {
+        assert_approx_eq!(f64, actual.0, expected.0, epsilon = EPSILON);
+        assert_approx_eq!(f64, actual.1, expected.1, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn using_spherical_mapping_on_3d_point() {
+        let cases = [
+            (Point::new(0.0, 0.0, -1.0), (0.0, 0.5)),
+            (Point::new(1.0, 0.0, 0.0), (0.25, 0.5)),
+            (Point::new(0.0, 0.0, 1.0), (0.5, 0.5)),
+            (Point::new(-1.0, 0.0, 0.0), (0.75, 0.5)),
+            (Point::new(0.0, 1.0, 0.0), (0.5, 1.0)),
+            (Point::new(0.0, -1.0, 0.0), (0.5, 0.0)),
+            (Point::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), (0.25, 0.75)),
+        ];
+
+        for (point, expected) in cases {
+            assert_uv(spherical_map(point), expected);
+        }
+    }
+
+    #[test]
+    fn using_planar_mapping_on_3d_point() {
+        let cases = [
+            (Point::new(0.25, 0.0, 0.5), (0.25, 0.5)),
+            (Point::new(0.25, 0.0, -0.25), (0.25, 0.75)),
+            (Point::new(0.25, 0.5, -0.25), (0.25, 0.75)),
+            (Point::new(1.25, 0.0, 0.5), (0.25, 0.5)),
+            (Point::new(0.25, 0.0, -1.75), (0.25, 0.25)),
+            (Point::new(1.0, 0.0, -1.0), (0.0, 0.0)),
+            (Point::new(0.0, 0.0, 0.0), (0.0, 0.0)),
+        ];
+
+        for (point, expected) in cases {
+            assert_uv(planar_map(point), expected);
+        }
+    }
+
+    #[test]
+    fn using_cylindrical_mapping_on_3d_point() {
+        let cases = [
+            (Point::new(0.0, 0.0, -1.0), (0.0, 0.0)),
+            (Point::new(0.0, 0.5, -1.0), (0.0, 0.5)),
+            (Point::new(0.0, 1.0, -1.0), (0.0, 0.0)),
+            (Point::new(FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), (0.125, 0.5)),
+            (Point::new(1.0, 0.5, 0.0), (0.25, 0.5)),
+            (Point::new(FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), (0.375, 0.5)),
+            (Point::new(0.0, -0.25, 1.0), (0.5, 0.75)),
+            (Point::new(-FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), (0.625, 0.5)),
+            (Point::new(-1.0, 1.25, 0.0), (0.75, 0.25)),
+            (
+                Point::new(-FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2),
+                (0.875, 0.5),
+            ),
+        ];
+
+        for (point, expected) in cases {
+            assert_uv(cylindrical_map(point), expected);
+        }
+    }
+
+    #[test]
+    fn uv_map_dispatches_to_mapping_functions() {
+        let p = Point::new(0.25, 0.5, -0.25);
+
+        assert_eq!(UvMap::Spherical.map(p), spherical_map(p));
+        assert_eq!(UvMap::Planar.map(p), planar_map(p));
+        assert_eq!(UvMap::Cylindrical.map(p), cylindrical_map(p));
+    }
+
+    #[test]
+    fn identifying_face_of_cube_from_point() {
+        let cases = [
+            (Point::new(-1.0, 0.5, -0.25), CubeFace::Left),
+            (Point::new(1.1, -0.75, 0.8), CubeFace::Right),
+            (Point::new(0.1, 0.6, 0.9), CubeFace::Front),
+            (Point::new(-0.7, 0.0, -2.0), CubeFace::Back),
+            (Point::new(0.5, 1.0, 0.9), CubeFace::Up),
+            (Point::new(-0.2, -1.3, 1.1), CubeFace::Down),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(CubeFace::from_point(point), expected);
+        }
+    }
+
+    #[test]
+    fn uv_mapping_each_face_of_cube() {
+        let cases = [
+            (Point::new(-0.5, 0.5, 1.0), CubeFace::Front, (0.25, 0.75)),
+            (Point::new(0.5, -0.5, 1.0), CubeFace::Front, (0.75, 0.25)),
+            (Point::new(0.5, 0.5, -1.0), CubeFace::Back, (0.25, 0.75)),
+            (Point::new(-0.5, -0.5, -1.0), CubeFace::Back, (0.75, 0.25)),
+            (Point::new(-1.0, 0.5, -0.5), CubeFace::Left, (0.25, 0.75)),
+            (Point::new(-1.0, -0.5, 0.5), CubeFace::Left, (0.75, 0.25)),
+            (Point::new(1.0, 0.5, 0.5), CubeFace::Right, (0.25, 0.75)),
+            (Point::new(1.0, -0.5, -0.5), CubeFace::Right, (0.75, 0.25)),
+            (Point::new(-0.5, 1.0, -0.5), CubeFace::Up, (0.25, 0.75)),
+            (Point::new(0.5, 1.0, 0.5), CubeFace::Up, (0.75, 0.25)),
+            (Point::new(-0.5, -1.0, 0.5), CubeFace::Down, (0.25, 0.75)),
+            (Point::new(0.5, -1.0, -0.5), CubeFace::Down, (0.75, 0.25)),
+        ];
+
+        for (point, face, expected) in cases {
+            let (actual_face, u, v) = cube_map(point);
+
+            assert_eq!(actual_face, face);
+            assert_uv((u, v), expected);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+
+use crate::{core::color::Color, utils::utils::DynEq};
+
+/// A color that varies over flat texture coordinates, wrapped around a
+/// shape by a `TextureMap` or `CubeMap` pattern.
+pub trait UvPattern: Debug + Send + Sync + DynEq {
+    /// Color at texture coordinates `u` and `v`, both between 0 and 1.
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color;
+}
+
+impl PartialEq for dyn UvPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_any())
+    }
+}
+
+/// A checkerboard of `width` by `height` squares over the texture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UvCheckers {
+    pub width: f64,
+    pub height: f64,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl UvCheckers {
+    pub fn new(width: f64, height: f64, a: Color, b: Color) -> Self {
+        UvCheckers {
+            width,
+            height,
+            a,
+            b,
+        }
+    }
+}
+
+impl UvPattern for UvCheckers {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color {
+        let sum = (u * self.width).floor() + (v * self.height).floor();
+
+        if sum.rem_euclid(2.0) == 0.0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+/// A flat color with a different one in each corner, to check how a texture
+/// is oriented on a shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UvAlignCheck {
+    pub main: Color,
+    pub upper_left: Color,
+    pub upper_right: Color,
+    pub bottom_left: Color,
+    pub bottom_right: Color,
+}
+
+impl UvAlignCheck {
+    pub fn new(
+        main: Color,
+        upper_left: Color,
+        upper_right: Color,
+        bottom_left: Color,
+        bottom_right: Color,
+    ) -> Self {
+        UvAlignCheck {
+            main,
+            upper_left,
+            upper_right,
+            bottom_left,
+            bottom_right,
+        }
+    }
+}
+
+impl UvPattern for UvAlignCheck {
+    fn uv_pattern_at(&self, u: f64, v: f64) -> Color {
+        match (u < 0.2, u > 0.8, v < 0.2, v > 0.8) {
+            (true, _, _, true) => self.upper_left,
+            (_, true, _, true) => self.upper_right,
+            (true, _, true, _) => self.bottom_left,
+            (_, true, true, _) => self.bottom_right,
+            _ => self.main,
+        }
+    }
+}
+
+#[cfg(test)]
+mod uv_pattern_tests {
+    use super::*;
+
+    #[test]
+    fn checker_pattern_in_2d() {
+        let pattern = UvCheckers::new(2.0, 2.0, Color::BLACK, Color::WHITE);
+        let cases = [
+            (0.0, 0.0, Color::BLACK),
+            (0.5, 0.0, Color::WHITE),
+            (0.0, 0.5, Color::WHITE),
+            (0.5, 0.5, Color::BLACK),
+            (1.0, 1.0, Color::BLACK),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(pattern.uv_pattern_at(u, v), expected);
+        }
+    }
+
+    #[test]
+    fn layout_of_align_check_pattern() {
+        let main = Color::WHITE;
+        let ul = Color::new(1.0, 0.0, 0.0);
+        let ur = Color::new(1.0, 1.0, 0.0);
+        let bl = Color::new(0.0, 1.0, 0.0);
+        let br = Color::new(0.0, 1.0, 1.0);
+        let pattern = UvAlignCheck::new(main, ul, ur, bl, br);
+        let cases = [
+            (0.5, 0.5, main),
+            (0.1, 0.9, ul),
+            (0.9, 0.9, ur),
+            (0.1, 0.1, bl),
+            (0.9, 0.1, br),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(pattern.uv_pattern_at(u, v), expected);
+        }
+    }
+
+    #[test]
+    fn comparing_uv_patterns_behind_trait_objects() {
+        let a: &dyn UvPattern = &UvCheckers::new(2.0, 2.0, Color::BLACK, Color::WHITE);
+        let b: &dyn UvPattern = &UvCheckers::new(2.0, 2.0, Color::BLACK, Color::WHITE);
+        let c: &dyn UvPattern = &UvCheckers::new(4.0, 2.0, Color::BLACK, Color::WHITE);
+
+        assert!(a == b);
+        assert!(a != c);
+    }
+}
@@ -1,8 +1,9 @@
-use crate::core::{point::Point, vector::Vector};
+use crate::core::{matrix4::Matrix4, point::Point, vector::Vector};
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Ray {
-    origin: Point,
-    direction: Vector,
+    pub origin: Point,
+    pub direction: Vector,
 }
 
 impl Ray {
@@ -13,11 +14,18 @@ impl Ray {
     pub fn at(&self, distance: f64) -> Point {
         self.origin + self.direction * distance
     }
+
+    pub fn transform(&self, m: &Matrix4) -> Self {
+        Ray {
+            origin: *m * self.origin,
+            direction: *m * self.direction,
+        }
+    }
 }
 
 #[cfg(test)]
 pub mod ray_tests {
-    use crate::core::{point::Point, vector::Vector};
+    use crate::core::{point::Point, transforms::Transforms, vector::Vector};
 
     use super::Ray;
 
@@ -30,4 +38,36 @@ pub mod ray_tests {
         assert_eq!(Point::new(1.0, 3.0, 4.0), r.at(-1.0));
         assert_eq!(Point::new(4.5, 3.0, 4.0), r.at(2.5));
     }
+
+    #[test]
+    fn translating_ray() {
+        let r = Ray::from(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+        let m = Transforms::translation(3.0, 4.0, 5.0);
+
+        let actual = r.transform(&m);
+
+        assert_eq!(actual.origin, Point::new(4.0, 6.0, 8.0));
+        assert_eq!(actual.direction, Vector::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn scaling_ray() {
+        let r = Ray::from(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+        let m = Transforms::scaling(2.0, 3.0, 4.0);
+
+        let actual = r.transform(&m);
+
+        assert_eq!(actual.origin, Point::new(2.0, 6.0, 12.0));
+        assert_eq!(actual.direction, Vector::new(0.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn transforming_does_not_mutate_original_ray() {
+        let r = Ray::from(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+
+        let _ = r.transform(&Transforms::translation(3.0, 4.0, 5.0));
+
+        assert_eq!(r.origin, Point::new(1.0, 2.0, 3.0));
+        assert_eq!(r.direction, Vector::new(0.0, 1.0, 0.0));
+    }
 }
@@ -0,0 +1,293 @@
+use crate::{
+    core::{point::Point, vector::Vector},
+    intersection::Intersection,
+    ray::Ray,
+    utils::utils::EPSILON,
+};
+
+use super::{
+    bounding_box::BoundingBox,
+    cylinder::check_cap,
+    shape::{Shape, ShapeProps},
+};
+
+/// A double-napped cone around the y axis of its object space, with its apex
+/// at the origin and a radius equal to `|y|`.
+///
+/// Like `Cylinder`, it can be truncated by `minimum` and `maximum` and
+/// capped with `closed`.
+#[derive(Debug)]
+pub struct Cone {
+    props: ShapeProps,
+    pub minimum: f64,
+    pub maximum: f64,
+    pub closed: bool,
+}
+
+impl Cone {
+    pub fn new() -> Self {
+        Cone {
+            props: ShapeProps::new(),
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn truncated(minimum: f64, maximum: f64, closed: bool) -> Self {
+        Cone {
+            minimum,
+            maximum,
+            closed,
+            ..Cone::new()
+        }
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+
+        for cap in [self.minimum, self.maximum] {
+            let t = (cap - ray.origin.y) / ray.direction.y;
+
+            if check_cap(ray, t, cap.abs()) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone::new()
+    }
+}
+
+impl Shape for Cone {
+    fn props(&self) -> &ShapeProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut ShapeProps {
+        &mut self.props
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let mut xs = vec![];
+
+        let (o, d) = (ray.origin, ray.direction);
+
+        let a = d.x.powi(2) - d.y.powi(2) + d.z.powi(2);
+        let b = 2.0 * o.x * d.x - 2.0 * o.y * d.y + 2.0 * o.z * d.z;
+        let c = o.x.powi(2) - o.y.powi(2) + o.z.powi(2);
+
+        let ts = if a.abs() < EPSILON {
+            // the ray is parallel to one of the cone's halves, so it can
+            // only cross the other half, once
+            if b.abs() < EPSILON {
+                vec![]
+            } else {
+                vec![-c / (2.0 * b)]
+            }
+        } else {
+            let discriminant = b.powi(2) - 4.0 * a * c;
+
+            if discriminant < 0.0 {
+                vec![]
+            } else {
+                let t0 = (-b - discriminant.sqrt()) / (2.0 * a);
+                let t1 = (-b + discriminant.sqrt()) / (2.0 * a);
+
+                vec![t0.min(t1), t0.max(t1)]
+            }
+        };
+
+        for t in ts {
+            let y = o.y + t * d.y;
+
+            if self.minimum < y && y < self.maximum {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+
+        self.intersect_caps(ray, &mut xs);
+
+        xs
+    }
+
+    fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+        let dist = point.x.powi(2) + point.z.powi(2);
+
+        if dist < self.maximum.powi(2) && point.y >= self.maximum - EPSILON {
+            return Vector::new(0.0, 1.0, 0.0);
+        }
+
+        if dist < self.minimum.powi(2) && point.y <= self.minimum + EPSILON {
+            return Vector::new(0.0, -1.0, 0.0);
+        }
+
+        let y = if point.y > 0.0 {
+            -dist.sqrt()
+        } else {
+            dist.sqrt()
+        };
+
+        Vector::new(point.x, y, point.z)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let limit = self.minimum.abs().max(self.maximum.abs());
+
+        BoundingBox::new(
+            Point::new(-limit, self.minimum, -limit),
+            Point::new(limit, self.maximum, limit),
+        )
+    }
+}
+
+#[cfg(test)]
+mod cone_tests {
+    use float_cmp::assert_approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn intersecting_cone_with_ray() {
+        let shape = Cone::new();
+        let cases = [
+            (
+                Point::new(0.0, 0.0, -5.0),
+                Vector::new(0.0, 0.0, 1.0),
+                5.0,
+                5.0,
+            ),
+            (
+                Point::new(0.0, 0.0, -5.0),
+                Vector::new(1.0, 1.0, 1.0),
+                8.66025,
+                8.66025,
+            ),
+            (
+                Point::new(1.0, 1.0, -5.0),
+                Vector::new(-0.5, -1.0, 1.0),
+                4.55006,
+                49.44994,
+            ),
+        ];
+
+        for (origin, direction, t0, t1) in cases {
+            let xs = shape.local_intersect(&Ray::from(origin, direction.normalize()));
+
+            assert_eq!(xs.len(), 2);
+            assert_approx_eq!(f64, xs[0].t, t0, epsilon = EPSILON);
+            assert_approx_eq!(f64, xs[1].t, t1, epsilon = EPSILON);
+        }
+    }
+
+    #[test]
+    fn ray_through_cone_apex() {
+        let shape = Cone::new();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = shape.local_intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(r.at(xs[0].t), Point::new(0.0, 0.0, 0.0));
+        assert_eq!(r.at(xs[1].t), Point::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn ray_along_cone_surface_through_apex() {
+        let shape = Cone::new();
+        let r = Ray::from(
+            Point::new(-1.0, -1.0, 0.0),
+            Vector::new(1.0, 1.0, 0.0).normalize(),
+        );
+
+        assert!(shape.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn intersecting_cone_with_ray_parallel_to_one_of_its_halves() {
+        let shape = Cone::new();
+        let r = Ray::from(
+            Point::new(0.0, 0.0, -1.0),
+            Vector::new(0.0, 1.0, 1.0).normalize(),
+        );
+
+        let xs = shape.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_approx_eq!(f64, xs[0].t, 2.0_f64.sqrt() / 4.0, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn intersecting_cone_end_caps() {
+        let shape = Cone::truncated(-0.5, 0.5, true);
+        let cases = [
+            (Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0), 0),
+            (Point::new(0.0, 0.0, -0.25), Vector::new(0.0, 1.0, 1.0), 2),
+            (Point::new(0.0, 0.0, -0.25), Vector::new(0.0, 1.0, 0.0), 4),
+        ];
+
+        for (origin, direction, count) in cases {
+            let xs = shape.local_intersect(&Ray::from(origin, direction.normalize()));
+
+            assert_eq!(xs.len(), count);
+        }
+    }
+
+    #[test]
+    fn normal_vector_on_cone() {
+        let shape = Cone::new();
+        let cases = [
+            (Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0)),
+            (
+                Point::new(1.0, 1.0, 1.0),
+                Vector::new(1.0, -(2.0_f64.sqrt()), 1.0),
+            ),
+            (Point::new(-1.0, -1.0, 0.0), Vector::new(-1.0, 1.0, 0.0)),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(
+                shape.local_normal_at(point, &Intersection::new(0.0, &shape)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn normal_vector_on_cone_end_caps() {
+        let shape = Cone::truncated(-1.0, 2.0, true);
+
+        assert_eq!(
+            shape.local_normal_at(Point::new(0.5, 2.0, 0.0), &Intersection::new(0.0, &shape)),
+            Vector::new(0.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            shape.local_normal_at(Point::new(0.0, -1.0, 0.5), &Intersection::new(0.0, &shape)),
+            Vector::new(0.0, -1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn cone_has_bounding_box() {
+        let b = Cone::new().bounds();
+
+        assert_eq!(
+            b.min,
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            b.max,
+            Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY)
+        );
+
+        let b = Cone::truncated(-5.0, 3.0, false).bounds();
+
+        assert_eq!(b.min, Point::new(-5.0, -5.0, -5.0));
+        assert_eq!(b.max, Point::new(5.0, 3.0, 5.0));
+    }
+}
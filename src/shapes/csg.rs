@@ -0,0 +1,412 @@
+use crate::{
+    core::{matrix4::Matrix4, point::Point, vector::Vector},
+    intersection::Intersection,
+    ray::Ray,
+};
+
+use super::{
+    bounding_box::BoundingBox,
+    shape::{same_shape, Shape, ShapeProps},
+};
+
+/// How the two shapes of a `Csg` are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsgOperation {
+    /// Everything inside either shape.
+    Union,
+    /// Only what is inside both shapes.
+    Intersection,
+    /// The left shape with the right one carved out of it.
+    Difference,
+}
+
+impl CsgOperation {
+    /// Whether an intersection belongs to the combined surface.
+    ///
+    /// `left_hit` tells which of the two shapes was hit, while `in_left` and
+    /// `in_right` tell whether the hit is inside the left and the right shape.
+    pub fn intersection_allowed(&self, left_hit: bool, in_left: bool, in_right: bool) -> bool {
+        match self {
+            CsgOperation::Union => (left_hit && !in_right) || (!left_hit && !in_left),
+            CsgOperation::Intersection => (left_hit && in_right) || (!left_hit && in_left),
+            CsgOperation::Difference => (left_hit && !in_right) || (!left_hit && in_left),
+        }
+    }
+}
+
+/// Constructive solid geometry: two shapes combined by a boolean operation.
+///
+/// Like groups, a `Csg` has no surface of its own and passes its transform
+/// down to both children. Each child keeps its own material, so the walls of
+/// a hole carved by a difference take the material of the shape that carved
+/// it.
+#[derive(Debug)]
+pub struct Csg {
+    props: ShapeProps,
+    operation: CsgOperation,
+    left: Box<dyn Shape>,
+    right: Box<dyn Shape>,
+    bounds: BoundingBox,
+}
+
+impl Csg {
+    pub fn new(
+        operation: CsgOperation,
+        left: impl Shape + 'static,
+        right: impl Shape + 'static,
+    ) -> Self {
+        Csg::from_boxed(operation, Box::new(left), Box::new(right))
+    }
+
+    pub fn from_boxed(
+        operation: CsgOperation,
+        left: Box<dyn Shape>,
+        right: Box<dyn Shape>,
+    ) -> Self {
+        let mut csg = Csg {
+            props: ShapeProps::new(),
+            operation,
+            left,
+            right,
+            bounds: BoundingBox::empty(),
+        };
+
+        csg.update_children();
+        csg.update_bounds();
+
+        csg
+    }
+
+    pub fn union(left: impl Shape + 'static, right: impl Shape + 'static) -> Self {
+        Csg::new(CsgOperation::Union, left, right)
+    }
+
+    pub fn intersection(left: impl Shape + 'static, right: impl Shape + 'static) -> Self {
+        Csg::new(CsgOperation::Intersection, left, right)
+    }
+
+    pub fn difference(left: impl Shape + 'static, right: impl Shape + 'static) -> Self {
+        Csg::new(CsgOperation::Difference, left, right)
+    }
+
+    pub fn operation(&self) -> CsgOperation {
+        self.operation
+    }
+
+    pub fn left(&self) -> &dyn Shape {
+        self.left.as_ref()
+    }
+
+    pub fn right(&self) -> &dyn Shape {
+        self.right.as_ref()
+    }
+
+    /// Keeps only the intersections, sorted by `t`, that lie on the surface
+    /// of the combined shape.
+    pub fn filter_intersections<'a>(&self, xs: &[Intersection<'a>]) -> Vec<Intersection<'a>> {
+        let mut in_left = false;
+        let mut in_right = false;
+        let mut result = vec![];
+
+        for i in xs {
+            let left_hit = self.left.includes(i.object);
+
+            if self
+                .operation
+                .intersection_allowed(left_hit, in_left, in_right)
+            {
+                result.push(*i);
+            }
+
+            // every hit on a child either enters or leaves it
+            if left_hit {
+                in_left = !in_left;
+            } else {
+                in_right = !in_right;
+            }
+        }
+
+        result
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.props.world_transform();
+
+        self.left.set_parent_transform(world_transform);
+        self.right.set_parent_transform(world_transform);
+    }
+
+    /// Differences and intersections never reach outside the left shape.
+    fn update_bounds(&mut self) {
+        self.bounds = self.left.parent_space_bounds();
+
+        if self.operation == CsgOperation::Union {
+            self.bounds.merge(&self.right.parent_space_bounds());
+        }
+    }
+}
+
+impl Shape for Csg {
+    fn props(&self) -> &ShapeProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut ShapeProps {
+        &mut self.props
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.props.set_transform(transform);
+        self.update_children();
+    }
+
+    fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        if self.props.parent_transform().data == parent_transform.data {
+            return;
+        }
+
+        self.props.set_parent_transform(parent_transform);
+        self.update_children();
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    fn build_bvh(&mut self) {
+        self.left.build_bvh();
+        self.right.build_bvh();
+        self.update_bounds();
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        same_shape(self, other) || self.left.includes(other) || self.right.includes(other)
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        if !self.bounds.intersects(ray) {
+            return vec![];
+        }
+
+        let mut xs: Vec<Intersection> = self
+            .left
+            .intersect(ray)
+            .into_iter()
+            .chain(self.right.intersect(ray))
+            .collect();
+
+        xs.sort_by(|a, b| a.t.total_cmp(&b.t));
+
+        self.filter_intersections(&xs)
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        unreachable!("csg shapes have no surface, normals are computed on their children")
+    }
+}
+
+#[cfg(test)]
+mod csg_tests {
+    use crate::{
+        core::transforms::Transforms,
+        shapes::{
+            cube::Cube, cylinder::Cylinder, group::Group, shape::shape_tests::TestShape,
+            sphere::Sphere,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn csg_is_created_with_operation_and_two_shapes() {
+        let s1 = Sphere::new();
+        let s2 = Cube::new();
+
+        let c = Csg::union(s1, s2);
+
+        assert_eq!(c.operation(), CsgOperation::Union);
+        assert!(c.includes(c.left()));
+        assert!(c.includes(c.right()));
+        assert!(!c.left().includes(c.right()));
+    }
+
+    #[test]
+    fn evaluating_rule_for_csg_operations() {
+        use CsgOperation::*;
+
+        let cases = [
+            (Union, true, true, true, false),
+            (Union, true, true, false, true),
+            (Union, true, false, true, false),
+            (Union, true, false, false, true),
+            (Union, false, true, true, false),
+            (Union, false, true, false, false),
+            (Union, false, false, true, true),
+            (Union, false, false, false, true),
+            (Intersection, true, true, true, true),
+            (Intersection, true, true, false, false),
+            (Intersection, true, false, true, true),
+            (Intersection, true, false, false, false),
+            (Intersection, false, true, true, true),
+            (Intersection, false, true, false, true),
+            (Intersection, false, false, true, false),
+            (Intersection, false, false, false, false),
+            (Difference, true, true, true, false),
+            (Difference, true, true, false, true),
+            (Difference, true, false, true, false),
+            (Difference, true, false, false, true),
+            (Difference, false, true, true, true),
+            (Difference, false, true, false, true),
+            (Difference, false, false, true, false),
+            (Difference, false, false, false, false),
+        ];
+
+        for (op, left_hit, in_left, in_right, expected) in cases {
+            let actual = op.intersection_allowed(left_hit, in_left, in_right);
+
+            assert_eq!(
+                actual, expected,
+                "{op:?} with lhit={left_hit}, inl={in_left}, inr={in_right}"
+            );
+        }
+    }
+
+    #[test]
+    fn filtering_list_of_intersections() {
+        let cases = [
+            (CsgOperation::Union, 0, 3),
+            (CsgOperation::Intersection, 1, 2),
+            (CsgOperation::Difference, 0, 1),
+        ];
+
+        for (operation, x0, x1) in cases {
+            let c = Csg::new(operation, Sphere::new(), Cube::new());
+            let xs = vec![
+                Intersection::new(1.0, c.left()),
+                Intersection::new(2.0, c.right()),
+                Intersection::new(3.0, c.left()),
+                Intersection::new(4.0, c.right()),
+            ];
+
+            let result = c.filter_intersections(&xs);
+
+            assert_eq!(result.len(), 2);
+            assert_eq!(result[0], xs[x0]);
+            assert_eq!(result[1], xs[x1]);
+        }
+    }
+
+    #[test]
+    fn ray_misses_csg_object() {
+        let c = Csg::union(Sphere::new(), Cube::new());
+        let r = Ray::from(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = c.local_intersect(&r);
+
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn ray_hits_csg_object() {
+        let s1 = Sphere::new();
+        let mut s2 = Sphere::new();
+        s2.set_transform(Transforms::translation(0.0, 0.0, 0.5));
+        let c = Csg::union(s1, s2);
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = c.local_intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 4.0);
+        assert!(same_shape(xs[0].object, c.left()));
+        assert_eq!(xs[1].t, 6.5);
+        assert!(same_shape(xs[1].object, c.right()));
+    }
+
+    #[test]
+    fn filtering_uses_children_of_nested_groups() {
+        let mut g = Group::new();
+        g.add_child(Sphere::new());
+        let mut s2 = Sphere::new();
+        s2.set_transform(Transforms::translation(0.0, 0.0, 0.5));
+        let c = Csg::difference(g, s2);
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = c.local_intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 4.0);
+        assert_eq!(xs[1].t, 4.5);
+        assert!(same_shape(xs[1].object, c.right()));
+    }
+
+    #[test]
+    fn carving_hole_in_cube_with_cylinder() {
+        let mut cylinder = Cylinder::truncated(-2.0, 2.0, true);
+        cylinder.set_transform(Transforms::scaling(0.5, 1.0, 0.5));
+        let c = Csg::difference(Cube::new(), cylinder);
+        let through_hole = Ray::from(Point::new(0.0, 5.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+        let beside_hole = Ray::from(Point::new(0.75, 5.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+
+        assert!(c.intersect(&through_hole).is_empty());
+        assert_eq!(c.intersect(&beside_hole).len(), 2);
+    }
+
+    #[test]
+    fn transforming_csg_moves_both_children() {
+        let mut c = Csg::union(Sphere::new(), Cube::new());
+
+        c.set_transform(Transforms::translation(0.0, 0.0, 10.0));
+
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = c.intersect(&r);
+        let comps = xs.hit().unwrap().prepare_computations(&r, &xs);
+
+        assert_eq!(*c.left().props().parent_transform(), *c.transform());
+        assert_eq!(*c.right().props().parent_transform(), *c.transform());
+        assert_eq!(comps.point, Point::new(0.0, 0.0, 9.0));
+        assert_eq!(comps.normalv, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn csg_has_bounding_box_containing_its_children() {
+        let left = Sphere::new();
+        let mut right = Sphere::new();
+        right.set_transform(Transforms::translation(2.0, 3.0, 4.0));
+
+        let union = Csg::union(left, right);
+
+        assert_eq!(union.bounds().min, Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(union.bounds().max, Point::new(3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    fn intersecting_csg_skips_children_if_box_is_missed() {
+        let left = TestShape::default();
+        let right = TestShape::default();
+        let saved_left = left.saved_ray.clone();
+        let saved_right = right.saved_ray.clone();
+        let c = Csg::difference(left, right);
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0));
+
+        c.intersect(&r);
+
+        assert!(saved_left.lock().unwrap().is_none());
+        assert!(saved_right.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn intersecting_csg_tests_children_if_box_is_hit() {
+        let left = TestShape::default();
+        let right = TestShape::default();
+        let saved_left = left.saved_ray.clone();
+        let saved_right = right.saved_ray.clone();
+        let c = Csg::difference(left, right);
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        c.intersect(&r);
+
+        assert!(saved_left.lock().unwrap().is_some());
+        assert!(saved_right.lock().unwrap().is_some());
+    }
+}
@@ -0,0 +1,602 @@
+use crate::{
+    core::{matrix4::Matrix4, point::Point, vector::Vector},
+    intersection::Intersection,
+    ray::Ray,
+};
+
+use super::{
+    bounding_box::BoundingBox,
+    shape::{same_shape, Shape, ShapeProps},
+};
+
+/// Groups with this many children or fewer are never subdivided.
+const BVH_LEAF_SIZE: usize = 4;
+
+/// Cost of testing a ray against a bounding box, relative to the cost of
+/// intersecting a primitive, used by the surface area heuristic.
+const BVH_TRAVERSAL_COST: f64 = 1.0;
+
+/// A collection of shapes intersected and transformed as one.
+///
+/// Groups have no surface of their own: rays are passed on to the children
+/// in the group's object space, and the intersections are always with them.
+///
+/// Groups nest, and transforms compose from the outermost group inwards. The
+/// combined transform is pushed down to every descendant whenever a child is
+/// added or a group is transformed, so a hit on a child can be converted back
+/// to world space without a link to its parents.
+///
+/// The bounds of the children are kept up to date as they are added, and
+/// rays that miss them skip the group entirely. `build_bvh` turns a flat
+/// group, such as a mesh, into a hierarchy of nested groups so most rays only
+/// visit a handful of children.
+#[derive(Debug, Default)]
+pub struct Group {
+    props: ShapeProps,
+    children: Vec<Box<dyn Shape>>,
+    bounds: BoundingBox,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Group {
+            props: ShapeProps::new(),
+            children: vec![],
+            bounds: BoundingBox::empty(),
+        }
+    }
+
+    pub fn add_child(&mut self, child: impl Shape + 'static) {
+        self.add_boxed_child(Box::new(child));
+    }
+
+    pub fn add_boxed_child(&mut self, mut child: Box<dyn Shape>) {
+        child.set_parent_transform(self.props.world_transform());
+        self.bounds.merge(&child.parent_space_bounds());
+        self.children.push(child);
+    }
+
+    pub fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.props.world_transform();
+
+        for child in self.children.iter_mut() {
+            child.set_parent_transform(world_transform);
+        }
+    }
+
+    /// Wraps `children` in a new group under this one, or returns the child
+    /// itself if there is only one.
+    fn node(&self, mut children: Vec<Box<dyn Shape>>) -> Box<dyn Shape> {
+        if children.len() == 1 {
+            return children.pop().unwrap();
+        }
+
+        let mut group = Group::new();
+        group.set_parent_transform(self.props.world_transform());
+
+        for child in self.subdivide(children) {
+            group.add_boxed_child(child);
+        }
+
+        Box::new(group)
+    }
+
+    /// Splits `children` in two along the axis and position that minimizes
+    /// the surface area heuristic, recursively, unless keeping them together
+    /// is cheaper.
+    fn subdivide(&self, children: Vec<Box<dyn Shape>>) -> Vec<Box<dyn Shape>> {
+        if children.len() <= BVH_LEAF_SIZE {
+            return children;
+        }
+
+        let mut items: Vec<(BoundingBox, Box<dyn Shape>)> = children
+            .into_iter()
+            .map(|child| (child.parent_space_bounds(), child))
+            .collect();
+
+        let mut parent = BoundingBox::empty();
+        for (bounds, _) in items.iter() {
+            parent.merge(bounds);
+        }
+
+        let parent_area = parent.surface_area();
+        // keeping every child in this group is the split to beat
+        let mut best_cost = items.len() as f64;
+        let mut best_split = None;
+
+        for axis in 0..3 {
+            sort_by_centroid(&mut items, axis);
+
+            // areas of the boxes around items[i..]
+            let mut right_areas = vec![0.0; items.len() + 1];
+            let mut right = BoundingBox::empty();
+
+            for (i, (bounds, _)) in items.iter().enumerate().rev() {
+                right.merge(bounds);
+                right_areas[i] = right.surface_area();
+            }
+
+            let mut left = BoundingBox::empty();
+
+            for split in 1..items.len() {
+                left.merge(&items[split - 1].0);
+
+                let cost = BVH_TRAVERSAL_COST
+                    + (left.surface_area() * split as f64
+                        + right_areas[split] * (items.len() - split) as f64)
+                        / parent_area;
+
+                if cost < best_cost {
+                    best_cost = cost;
+                    best_split = Some((axis, split));
+                }
+            }
+        }
+
+        let children = |items: Vec<(BoundingBox, Box<dyn Shape>)>| {
+            items
+                .into_iter()
+                .map(|(_, child)| child)
+                .collect::<Vec<_>>()
+        };
+
+        match best_split {
+            Some((axis, split)) => {
+                sort_by_centroid(&mut items, axis);
+                let right = items.split_off(split);
+
+                vec![self.node(children(items)), self.node(children(right))]
+            }
+            _ => children(items),
+        }
+    }
+}
+
+fn sort_by_centroid(items: &mut [(BoundingBox, Box<dyn Shape>)], axis: usize) {
+    let key = |bounds: &BoundingBox| {
+        let c = bounds.centroid();
+        [c.x, c.y, c.z][axis]
+    };
+
+    items.sort_by(|(a, _), (b, _)| key(a).total_cmp(&key(b)));
+}
+
+impl Shape for Group {
+    fn props(&self) -> &ShapeProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut ShapeProps {
+        &mut self.props
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.props.set_transform(transform);
+        self.update_children();
+    }
+
+    fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        if self.props.parent_transform().data == parent_transform.data {
+            return;
+        }
+
+        self.props.set_parent_transform(parent_transform);
+        self.update_children();
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    /// Children with infinite bounds, like planes, can't be partitioned and
+    /// stay directly in this group.
+    fn build_bvh(&mut self) {
+        let children = std::mem::take(&mut self.children);
+        self.bounds = BoundingBox::empty();
+
+        let (mut finite, infinite): (Vec<_>, Vec<_>) = children
+            .into_iter()
+            .partition(|child| child.parent_space_bounds().is_finite());
+
+        for child in finite.iter_mut() {
+            child.build_bvh();
+        }
+
+        for child in infinite.into_iter().chain(self.subdivide(finite)) {
+            self.add_boxed_child(child);
+        }
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        same_shape(self, other) || self.children.iter().any(|child| child.includes(other))
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        if !self.bounds.intersects(ray) {
+            return vec![];
+        }
+
+        self.children
+            .iter()
+            .flat_map(|child| child.intersect(ray))
+            .collect()
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        unreachable!("groups have no surface, normals are computed on their children")
+    }
+}
+
+#[cfg(test)]
+mod group_tests {
+    use std::{
+        f64::consts::PI,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use crate::{
+        core::transforms::Transforms,
+        shapes::{
+            cube::Cube, cylinder::Cylinder, plane::Plane, shape::same_shape,
+            shape::shape_tests::TestShape, sphere::Sphere,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn creating_group() {
+        let g = Group::new();
+
+        assert_eq!(*g.transform(), Matrix4::IDENTITY);
+        assert!(g.is_empty());
+    }
+
+    #[test]
+    fn adding_child_to_group() {
+        let mut g = Group::new();
+
+        g.add_child(Sphere::new());
+
+        assert_eq!(g.len(), 1);
+    }
+
+    #[test]
+    fn intersecting_ray_with_empty_group() {
+        let g = Group::new();
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(g.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn intersecting_ray_with_nonempty_group() {
+        let mut g = Group::new();
+        g.add_child(Sphere::new());
+        let mut s2 = Sphere::new();
+        s2.set_transform(Transforms::translation(0.0, 0.0, -3.0));
+        g.add_child(s2);
+        let mut s3 = Sphere::new();
+        s3.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+        g.add_child(s3);
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = g.intersect(&r);
+
+        let s1 = g.children()[0].as_ref();
+        let s2 = g.children()[1].as_ref();
+
+        assert_eq!(xs.len(), 4);
+        assert!(same_shape(xs[0].object, s2));
+        assert!(same_shape(xs[1].object, s2));
+        assert!(same_shape(xs[2].object, s1));
+        assert!(same_shape(xs[3].object, s1));
+    }
+
+    #[test]
+    fn intersecting_transformed_group() {
+        let mut g = Group::new();
+        g.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+        let mut s = Sphere::new();
+        s.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+        g.add_child(s);
+        let r = Ray::from(Point::new(10.0, 0.0, -10.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = g.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+    }
+
+    fn nested_sphere() -> Group {
+        let mut s = Sphere::new();
+        s.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+
+        let mut g2 = Group::new();
+        g2.set_transform(Transforms::scaling(1.0, 2.0, 3.0));
+        g2.add_child(s);
+
+        let mut g1 = Group::new();
+        g1.set_transform(Transforms::rotation_y(PI / 2.0));
+        g1.add_child(g2);
+
+        g1
+    }
+
+    #[test]
+    fn adding_child_passes_down_group_transform() {
+        let mut g = Group::new();
+        g.set_transform(Transforms::translation(1.0, 2.0, 3.0));
+
+        g.add_child(Sphere::new());
+
+        assert_eq!(
+            *g.children()[0].props().parent_transform(),
+            Transforms::translation(1.0, 2.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn transforming_group_updates_nested_children() {
+        let g1 = nested_sphere();
+        let g2 = g1.children()[0].as_ref();
+
+        assert_eq!(
+            *g2.props().parent_transform(),
+            Transforms::rotation_y(PI / 2.0)
+        );
+    }
+
+    #[test]
+    fn finding_normal_on_child_object() {
+        let g1 = nested_sphere();
+        let k = 3.0_f64.sqrt() / 3.0;
+        let expected = Vector::new(2.0 / 7.0, 3.0 / 7.0, -6.0 / 7.0);
+
+        // the world point sits on the sphere where its object-space normal is (k, k, k)
+        let world_point = Transforms::rotation_y(PI / 2.0)
+            * Transforms::scaling(1.0, 2.0, 3.0)
+            * Transforms::translation(5.0, 0.0, 0.0)
+            * Point::new(k, k, k);
+        let r = Ray::from(world_point + expected * 0.5, -expected);
+
+        let xs = g1.intersect(&r);
+        let hit = xs.hit().unwrap();
+        let comps = hit.prepare_computations(&r, &xs);
+
+        assert_eq!(comps.point, world_point);
+        assert_eq!(comps.normalv, expected);
+    }
+
+    #[test]
+    fn normals_follow_group_transformed_after_children_were_added() {
+        let mut g = Group::new();
+        g.add_child(Sphere::new());
+        g.set_transform(Transforms::translation(0.0, 0.0, 10.0));
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = g.intersect(&r);
+        let comps = xs.hit().unwrap().prepare_computations(&r, &xs);
+
+        assert_eq!(comps.point, Point::new(0.0, 0.0, 9.0));
+        assert_eq!(comps.normalv, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn moving_car_moves_every_wheel() {
+        let mut car = Group::new();
+        let mut body = Cube::new();
+        body.set_transform(
+            Transforms::translation(0.0, 1.5, 0.0) * Transforms::scaling(2.0, 0.5, 1.0),
+        );
+        car.add_child(body);
+
+        for (x, z) in [(-1.5, -1.0), (1.5, -1.0), (-1.5, 1.0), (1.5, 1.0)] {
+            let mut tire = Cylinder::truncated(-0.1, 0.1, true);
+            tire.set_transform(Transforms::scaling(0.5, 1.0, 0.5));
+
+            let mut wheel = Group::new();
+            wheel.set_transform(
+                Transforms::translation(x, 0.5, z) * Transforms::rotation_x(PI / 2.0),
+            );
+            wheel.add_child(tire);
+
+            car.add_child(wheel);
+        }
+
+        car.set_transform(Transforms::translation(10.0, 0.0, 0.0));
+
+        // straight up into the bottom of the front left tire
+        let r = Ray::from(Point::new(8.5, -5.0, -1.0), Vector::new(0.0, 1.0, 0.0));
+
+        let xs = car.intersect(&r);
+        let comps = xs.hit().unwrap().prepare_computations(&r, &xs);
+
+        assert_eq!(comps.point, Point::new(8.5, 0.0, -1.0));
+        assert_eq!(comps.normalv, Vector::new(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn group_has_bounding_box_containing_its_children() {
+        let mut s = Sphere::new();
+        s.set_transform(
+            Transforms::translation(2.0, 5.0, -3.0) * Transforms::scaling(2.0, 2.0, 2.0),
+        );
+        let mut c = Cylinder::truncated(-2.0, 2.0, false);
+        c.set_transform(
+            Transforms::translation(-4.0, -1.0, 4.0) * Transforms::scaling(0.5, 1.0, 0.5),
+        );
+        let mut g = Group::new();
+        g.add_child(s);
+        g.add_child(c);
+
+        let b = g.bounds();
+
+        assert_eq!(b.min, Point::new(-4.5, -3.0, -5.0));
+        assert_eq!(b.max, Point::new(4.0, 7.0, 4.5));
+    }
+
+    #[test]
+    fn intersecting_group_skips_children_if_box_is_missed() {
+        let child = TestShape::default();
+        let saved_ray = child.saved_ray.clone();
+        let mut g = Group::new();
+        g.add_child(child);
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0));
+
+        g.intersect(&r);
+
+        assert!(saved_ray.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn intersecting_group_tests_children_if_box_is_hit() {
+        let child = TestShape::default();
+        let saved_ray = child.saved_ray.clone();
+        let mut g = Group::new();
+        g.add_child(child);
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        g.intersect(&r);
+
+        assert!(saved_ray.lock().unwrap().is_some());
+    }
+
+    #[test]
+    fn group_includes_nested_children() {
+        let mut inner = Group::new();
+        inner.add_child(Sphere::new());
+        let mut outer = Group::new();
+        outer.add_child(inner);
+        let other = Sphere::new();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = outer.intersect(&r);
+
+        assert!(outer.includes(xs[0].object));
+        assert!(outer.includes(outer.children()[0].as_ref()));
+        assert!(!outer.includes(&other));
+    }
+
+    fn row_of_spheres(count: usize) -> Group {
+        let mut g = Group::new();
+
+        for i in 0..count {
+            let mut s = Sphere::new();
+            s.set_transform(Transforms::translation(3.0 * i as f64, 0.0, 0.0));
+            g.add_child(s);
+        }
+
+        g
+    }
+
+    #[test]
+    fn small_groups_are_not_subdivided() {
+        let mut g = row_of_spheres(BVH_LEAF_SIZE);
+
+        g.build_bvh();
+
+        assert_eq!(g.len(), BVH_LEAF_SIZE);
+    }
+
+    #[test]
+    fn building_bvh_splits_children_in_two() {
+        let mut g = row_of_spheres(64);
+
+        g.build_bvh();
+
+        assert_eq!(g.len(), 2);
+        assert_eq!(g.bounds(), row_of_spheres(64).bounds());
+        assert!(g.children()[0].bounds().max.x < g.children()[1].bounds().min.x);
+    }
+
+    #[test]
+    fn building_bvh_keeps_infinite_shapes_in_group() {
+        let mut g = row_of_spheres(64);
+        g.add_child(Plane::new());
+
+        g.build_bvh();
+
+        assert_eq!(g.len(), 3);
+        assert!(!g.children()[0].bounds().is_finite());
+    }
+
+    #[test]
+    fn bvh_tests_few_children_however_large_the_group() {
+        // average number of children tested by a ray aimed at each child
+        let tests_per_ray = |count: usize, bvh: bool| {
+            let intersections = Arc::new(AtomicUsize::new(0));
+            let mut g = Group::new();
+            for i in 0..count {
+                let mut s = TestShape::default();
+                s.set_transform(Transforms::translation(3.0 * i as f64, 0.0, 0.0));
+                s.intersections = intersections.clone();
+                g.add_child(s);
+            }
+            if bvh {
+                g.build_bvh();
+            }
+
+            for i in 0..count {
+                let origin = Point::new(3.0 * i as f64, 0.0, -5.0);
+                g.intersect(&Ray::from(origin, Vector::new(0.0, 0.0, 1.0)));
+            }
+
+            intersections.load(Ordering::Relaxed) as f64 / count as f64
+        };
+
+        assert_eq!(tests_per_ray(64, false), 64.0);
+        assert_eq!(tests_per_ray(1024, false), 1024.0);
+
+        let small = tests_per_ray(64, true);
+        let large = tests_per_ray(1024, true);
+
+        // 16 times the children, but nowhere near 16 times the work
+        assert!(small <= BVH_LEAF_SIZE as f64);
+        assert!(large < small * 2.0, "{small} tests per ray grew to {large}");
+    }
+
+    #[test]
+    fn building_bvh_does_not_change_intersections() {
+        let mut g = row_of_spheres(64);
+        g.set_transform(Transforms::rotation_y(PI / 3.0));
+        let mut bvh = row_of_spheres(64);
+        bvh.set_transform(Transforms::rotation_y(PI / 3.0));
+        bvh.build_bvh();
+
+        for i in 0..64 {
+            let target = Transforms::rotation_y(PI / 3.0) * Point::new(3.0 * i as f64, 0.5, 0.0);
+            let r = Ray::from(
+                target + Vector::new(0.0, 0.0, -10.0),
+                Vector::new(0.0, 0.0, 1.0),
+            );
+
+            let expected = g.intersect(&r);
+            let actual = bvh.intersect(&r);
+
+            assert_eq!(actual.len(), expected.len());
+
+            for (a, e) in actual.iter().zip(expected.iter()) {
+                assert_eq!(a.t, e.t);
+                assert_eq!(
+                    a.prepare_computations(&r, &actual).normalv,
+                    e.prepare_computations(&r, &expected).normalv
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    core::{point::Point, vector::Vector},
+    intersection::Intersection,
+    ray::Ray,
+    utils::utils::EPSILON,
+};
+
+use super::{
+    bounding_box::BoundingBox,
+    shape::{Shape, ShapeProps},
+};
+
+/// An infinite plane spanning the x and z axes of its object space.
+#[derive(Debug, Default)]
+pub struct Plane {
+    props: ShapeProps,
+}
+
+impl Plane {
+    pub fn new() -> Self {
+        Plane {
+            props: ShapeProps::new(),
+        }
+    }
+}
+
+impl Shape for Plane {
+    fn props(&self) -> &ShapeProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut ShapeProps {
+        &mut self.props
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        // parallel or coplanar rays never hit a plane with no thickness
+        if ray.direction.y.abs() < EPSILON {
+            return vec![];
+        }
+
+        let t = -ray.origin.y / ray.direction.y;
+
+        vec![Intersection::new(t, self)]
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        Vector::new(0.0, 1.0, 0.0)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 0.0, f64::INFINITY),
+        )
+    }
+}
+
+#[cfg(test)]
+mod plane_tests {
+    use crate::shapes::shape::same_shape;
+
+    use super::*;
+
+    #[test]
+    fn normal_of_plane_is_constant_everywhere() {
+        let p = Plane::new();
+        let expected = Vector::new(0.0, 1.0, 0.0);
+
+        assert_eq!(
+            p.local_normal_at(Point::new(0.0, 0.0, 0.0), &Intersection::new(0.0, &p)),
+            expected
+        );
+        assert_eq!(
+            p.local_normal_at(Point::new(10.0, 0.0, -10.0), &Intersection::new(0.0, &p)),
+            expected
+        );
+        assert_eq!(
+            p.local_normal_at(Point::new(-5.0, 0.0, 150.0), &Intersection::new(0.0, &p)),
+            expected
+        );
+    }
+
+    #[test]
+    fn intersect_with_ray_parallel_to_plane() {
+        let p = Plane::new();
+        let r = Ray::from(Point::new(0.0, 10.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(p.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn intersect_with_coplanar_ray() {
+        let p = Plane::new();
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(p.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn intersect_with_nearly_parallel_ray() {
+        let p = Plane::new();
+        let r = Ray::from(
+            Point::new(0.0, 1.0, 0.0),
+            Vector::new(1.0, EPSILON / 10.0, 0.0),
+        );
+
+        assert!(p.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn ray_intersecting_plane_from_above() {
+        let p = Plane::new();
+        let r = Ray::from(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+
+        let xs = p.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 1.0);
+        assert!(same_shape(xs[0].object, &p));
+    }
+
+    #[test]
+    fn ray_intersecting_plane_from_below() {
+        let p = Plane::new();
+        let r = Ray::from(Point::new(0.0, -1.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+
+        let xs = p.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 1.0);
+        assert!(same_shape(xs[0].object, &p));
+    }
+
+    #[test]
+    fn plane_has_bounding_box() {
+        let p = Plane::new();
+
+        let b = p.bounds();
+
+        assert_eq!(b.min, Point::new(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY));
+        assert_eq!(b.max, Point::new(f64::INFINITY, 0.0, f64::INFINITY));
+    }
+}
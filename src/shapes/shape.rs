@@ -0,0 +1,450 @@
+use std::fmt::Debug;
+
+use crate::{
+    core::{matrix4::Matrix4, point::Point, vector::Vector},
+    intersection::{Intersection, Intersections},
+    material::Material,
+    ray::Ray,
+};
+
+use super::bounding_box::BoundingBox;
+
+/// State shared by every shape: its material, whether it casts shadows and
+/// its object-space transform along with the cached inverse and
+/// inverse-transpose.
+///
+/// Shapes nested in groups also keep the combined transform of all their
+/// ancestors, so points and normals can go straight between world and object
+/// space without walking up the hierarchy for every hit.
+#[derive(Debug, Clone)]
+pub struct ShapeProps {
+    transform: Matrix4,
+    inverse: Matrix4,
+    inverse_transpose: Matrix4,
+    parent_transform: Matrix4,
+    world_inverse: Matrix4,
+    world_inverse_transpose: Matrix4,
+    material: Material,
+    casts_shadow: bool,
+}
+
+impl ShapeProps {
+    pub fn new() -> Self {
+        ShapeProps {
+            transform: Matrix4::IDENTITY,
+            inverse: Matrix4::IDENTITY,
+            inverse_transpose: Matrix4::IDENTITY,
+            parent_transform: Matrix4::IDENTITY,
+            world_inverse: Matrix4::IDENTITY,
+            world_inverse_transpose: Matrix4::IDENTITY,
+            material: Material::new(),
+            casts_shadow: true,
+        }
+    }
+
+    pub fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    pub fn inverse_transpose(&self) -> &Matrix4 {
+        &self.inverse_transpose
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.transform = transform;
+        self.inverse = transform.inverse();
+        self.inverse_transpose = self.inverse.transposed();
+        self.update_world_transform();
+    }
+
+    /// Combined transform of every group this shape is nested in.
+    pub fn parent_transform(&self) -> &Matrix4 {
+        &self.parent_transform
+    }
+
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        if self.parent_transform.data == parent_transform.data {
+            return;
+        }
+
+        self.parent_transform = parent_transform;
+        self.update_world_transform();
+    }
+
+    /// Transform from this shape's object space all the way to world space.
+    pub fn world_transform(&self) -> Matrix4 {
+        self.parent_transform * self.transform
+    }
+
+    pub fn world_inverse(&self) -> &Matrix4 {
+        &self.world_inverse
+    }
+
+    pub fn world_inverse_transpose(&self) -> &Matrix4 {
+        &self.world_inverse_transpose
+    }
+
+    fn update_world_transform(&mut self) {
+        self.world_inverse = self.world_transform().inverse();
+        self.world_inverse_transpose = self.world_inverse.transposed();
+    }
+
+    pub fn material(&self) -> &Material {
+        &self.material
+    }
+
+    pub fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    pub fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    pub fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    pub fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+}
+
+impl Default for ShapeProps {
+    fn default() -> Self {
+        ShapeProps::new()
+    }
+}
+
+/// A primitive that can be intersected by rays.
+///
+/// Implementors only deal with object space through `local_intersect` and
+/// `local_normal_at`; converting rays, points and normals from and to world
+/// space is handled here.
+pub trait Shape: Debug + Send + Sync {
+    fn props(&self) -> &ShapeProps;
+
+    fn props_mut(&mut self) -> &mut ShapeProps;
+
+    /// Intersections of an object-space ray with this shape, in any order.
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>>;
+
+    /// Normal of this shape at an object-space point on its surface.
+    ///
+    /// `hit` is the intersection that produced the point, for shapes that
+    /// interpolate their normals across the surface.
+    fn local_normal_at(&self, point: Point, hit: &Intersection) -> Vector;
+
+    /// Object-space box enclosing the whole shape.
+    fn bounds(&self) -> BoundingBox;
+
+    /// Box enclosing the shape in the space of the group that contains it.
+    fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transform(self.transform())
+    }
+
+    /// Reorganizes the shape's children, if any, into a bounding volume
+    /// hierarchy.
+    fn build_bvh(&mut self) {}
+
+    /// Whether `other` is this shape or one of its descendants.
+    fn includes(&self, other: &dyn Shape) -> bool {
+        std::ptr::eq(
+            self as *const Self as *const (),
+            other as *const dyn Shape as *const (),
+        )
+    }
+
+    fn transform(&self) -> &Matrix4 {
+        self.props().transform()
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        self.props_mut().set_transform(transform);
+    }
+
+    /// Called by the group this shape is added to, and again whenever one of
+    /// its ancestors is transformed. Shapes with children must pass the
+    /// change down to them.
+    fn set_parent_transform(&mut self, parent_transform: Matrix4) {
+        self.props_mut().set_parent_transform(parent_transform);
+    }
+
+    fn material(&self) -> &Material {
+        self.props().material()
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.props_mut().set_material(material);
+    }
+
+    fn casts_shadow(&self) -> bool {
+        self.props().casts_shadow()
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.props_mut().set_casts_shadow(casts_shadow);
+    }
+
+    fn intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let local_ray = ray.transform(self.props().inverse());
+
+        Intersections::new(self.local_intersect(&local_ray))
+    }
+
+    fn world_to_object(&self, point: Point) -> Point {
+        *self.props().world_inverse() * point
+    }
+
+    fn normal_to_world(&self, normal: Vector) -> Vector {
+        (*self.props().world_inverse_transpose() * normal).normalize()
+    }
+
+    fn normal_at(&self, world_point: Point, hit: &Intersection) -> Vector {
+        let local_point = self.world_to_object(world_point);
+        let local_normal = self.local_normal_at(local_point, hit);
+
+        self.normal_to_world(local_normal)
+    }
+}
+
+/// Whether two shape references point to the same object.
+pub fn same_shape(a: &dyn Shape, b: &dyn Shape) -> bool {
+    std::ptr::eq(
+        a as *const dyn Shape as *const (),
+        b as *const dyn Shape as *const (),
+    )
+}
+
+#[cfg(test)]
+pub mod shape_tests {
+    use std::{
+        f64::consts::{FRAC_1_SQRT_2, PI},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+    };
+
+    use crate::core::transforms::Transforms;
+
+    use super::*;
+
+    /// A shape that records the object-space ray it was intersected with,
+    /// and counts how many times it was intersected.
+    ///
+    /// The records are shared, so they can still be checked once the shape
+    /// has been moved into a group.
+    #[derive(Debug, Default)]
+    pub struct TestShape {
+        props: ShapeProps,
+        pub saved_ray: Arc<Mutex<Option<Ray>>>,
+        pub intersections: Arc<AtomicUsize>,
+    }
+
+    impl Shape for TestShape {
+        fn props(&self) -> &ShapeProps {
+            &self.props
+        }
+
+        fn props_mut(&mut self) -> &mut ShapeProps {
+            &mut self.props
+        }
+
+        fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+            *self.saved_ray.lock().unwrap() = Some(*ray);
+            self.intersections.fetch_add(1, Ordering::Relaxed);
+
+            vec![]
+        }
+
+        fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+            Vector::new(point.x, point.y, point.z)
+        }
+
+        fn bounds(&self) -> BoundingBox {
+            BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+        }
+    }
+
+    #[test]
+    fn default_transformation() {
+        let s = TestShape::default();
+
+        assert_eq!(*s.transform(), Matrix4::IDENTITY);
+    }
+
+    #[test]
+    fn assigning_transformation() {
+        let mut s = TestShape::default();
+
+        s.set_transform(Transforms::translation(2.0, 3.0, 4.0));
+
+        assert_eq!(*s.transform(), Transforms::translation(2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn default_material() {
+        let s = TestShape::default();
+
+        assert_eq!(*s.material(), Material::new());
+    }
+
+    #[test]
+    fn assigning_material() {
+        let mut s = TestShape::default();
+        let mut m = Material::new();
+        m.ambient = 1.0;
+
+        s.set_material(m.clone());
+
+        assert_eq!(*s.material(), m);
+    }
+
+    #[test]
+    fn shapes_cast_shadows_by_default() {
+        let mut s = TestShape::default();
+
+        assert!(s.casts_shadow());
+
+        s.set_casts_shadow(false);
+
+        assert!(!s.casts_shadow());
+    }
+
+    #[test]
+    fn intersecting_scaled_shape_with_ray() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+
+        s.intersect(&r);
+
+        let saved = s.saved_ray.lock().unwrap().unwrap();
+
+        assert_eq!(saved.origin, Point::new(0.0, 0.0, -2.5));
+        assert_eq!(saved.direction, Vector::new(0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn intersecting_translated_shape_with_ray() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+
+        s.intersect(&r);
+
+        let saved = s.saved_ray.lock().unwrap().unwrap();
+
+        assert_eq!(saved.origin, Point::new(-5.0, 0.0, -5.0));
+        assert_eq!(saved.direction, Vector::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn computing_normal_on_translated_shape() {
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::translation(0.0, 1.0, 0.0));
+
+        let actual = s.normal_at(
+            Point::new(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+            &Intersection::new(0.0, &s),
+        );
+        let expected = Vector::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn computing_normal_on_transformed_shape() {
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::scaling(1.0, 0.5, 1.0) * Transforms::rotation_z(PI / 5.0));
+
+        let actual = s.normal_at(
+            Point::new(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0),
+            &Intersection::new(0.0, &s),
+        );
+        let expected = Vector::new(0.0, 0.97014, -0.24254);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn computing_normal_on_sheared_shape() {
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+
+        let actual = s.normal_at(Point::new(2.0, 1.0, 0.0), &Intersection::new(0.0, &s));
+        let expected = Vector::new(1.0, 0.0, 0.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn converting_point_from_world_to_object_space() {
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+        s.set_parent_transform(
+            Transforms::rotation_y(PI / 2.0) * Transforms::scaling(2.0, 2.0, 2.0),
+        );
+
+        let actual = s.world_to_object(Point::new(-2.0, 0.0, -10.0));
+        let expected = Point::new(0.0, 0.0, -1.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn converting_normal_from_object_to_world_space() {
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+        s.set_parent_transform(
+            Transforms::rotation_y(PI / 2.0) * Transforms::scaling(1.0, 2.0, 3.0),
+        );
+        let k = 3.0_f64.sqrt() / 3.0;
+
+        let actual = s.normal_to_world(Vector::new(k, k, k));
+        let expected = Vector::new(2.0 / 7.0, 3.0 / 7.0, -6.0 / 7.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parent_transform_does_not_change_local_transform() {
+        let mut s = TestShape::default();
+        s.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+
+        s.set_parent_transform(Transforms::scaling(2.0, 2.0, 2.0));
+
+        assert_eq!(*s.transform(), Transforms::translation(5.0, 0.0, 0.0));
+        assert_eq!(
+            s.props().world_transform(),
+            Transforms::scaling(2.0, 2.0, 2.0) * Transforms::translation(5.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn querying_shape_bounding_box_in_parent_space() {
+        let mut s = TestShape::default();
+        s.set_transform(
+            Transforms::translation(1.0, -3.0, 5.0) * Transforms::scaling(0.5, 2.0, 4.0),
+        );
+
+        let actual = s.parent_space_bounds();
+
+        assert_eq!(actual.min, Point::new(0.5, -5.0, 1.0));
+        assert_eq!(actual.max, Point::new(1.5, -1.0, 9.0));
+    }
+
+    #[test]
+    fn shape_includes_itself() {
+        let s = TestShape::default();
+        let other = TestShape::default();
+
+        assert!(s.includes(&s));
+        assert!(!s.includes(&other));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    core::{point::Point, vector::Vector},
+    intersection::Intersection,
+    ray::Ray,
+};
+
+use super::{
+    bounding_box::BoundingBox,
+    shape::{Shape, ShapeProps},
+    triangle::intersect_triangle,
+};
+
+/// A triangle with a normal at each vertex, interpolated across its surface
+/// to fake curvature on low-poly meshes.
+#[derive(Debug)]
+pub struct SmoothTriangle {
+    props: ShapeProps,
+    pub p1: Point,
+    pub p2: Point,
+    pub p3: Point,
+    pub n1: Vector,
+    pub n2: Vector,
+    pub n3: Vector,
+    pub e1: Vector,
+    pub e2: Vector,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> Self {
+        SmoothTriangle {
+            props: ShapeProps::new(),
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+        }
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn props(&self) -> &ShapeProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut ShapeProps {
+        &mut self.props
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        match intersect_triangle(ray, self.p1, self.e1, self.e2) {
+            Some((t, u, v)) => vec![Intersection::with_uv(t, self, u, v)],
+            None => vec![],
+        }
+    }
+
+    fn local_normal_at(&self, _point: Point, hit: &Intersection) -> Vector {
+        self.n2 * hit.u + self.n3 * hit.v + self.n1 * (1.0 - hit.u - hit.v)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+
+        for point in [self.p1, self.p2, self.p3] {
+            bounds.add_point(point);
+        }
+
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod smooth_triangle_tests {
+    use float_cmp::assert_approx_eq;
+
+    use crate::{intersection::Intersections, utils::utils::EPSILON};
+
+    use super::*;
+
+    fn smooth_triangle() -> SmoothTriangle {
+        SmoothTriangle::new(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            Vector::new(1.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn constructing_smooth_triangle() {
+        let tri = smooth_triangle();
+
+        assert_eq!(tri.p1, Point::new(0.0, 1.0, 0.0));
+        assert_eq!(tri.n1, Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(tri.e1, Vector::new(-1.0, -1.0, 0.0));
+        assert_eq!(tri.e2, Vector::new(1.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn intersection_with_smooth_triangle_stores_uv() {
+        let tri = smooth_triangle();
+        let r = Ray::from(Point::new(-0.2, 0.3, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = tri.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_approx_eq!(f64, xs[0].u, 0.45, epsilon = EPSILON);
+        assert_approx_eq!(f64, xs[0].v, 0.25, epsilon = EPSILON);
+    }
+
+    #[test]
+    fn smooth_triangle_uses_uv_to_interpolate_normal() {
+        let tri = smooth_triangle();
+        let i = Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+
+        let actual = tri.normal_at(Point::new(0.0, 0.0, 0.0), &i);
+        let expected = Vector::new(-0.5547, 0.83205, 0.0);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn preparing_normal_on_smooth_triangle() {
+        let tri = smooth_triangle();
+        let i = Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+        let r = Ray::from(Point::new(-0.2, 0.3, -2.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert_eq!(comps.normalv, Vector::new(-0.5547, 0.83205, 0.0));
+    }
+}
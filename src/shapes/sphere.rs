@@ -0,0 +1,273 @@
+use crate::{
+    core::{point::Point, vector::Vector},
+    intersection::Intersection,
+    ray::Ray,
+};
+
+use super::{
+    bounding_box::BoundingBox,
+    shape::{Shape, ShapeProps},
+};
+
+/// A unit sphere centered at the origin of its object space.
+#[derive(Debug, Default)]
+pub struct Sphere {
+    props: ShapeProps,
+}
+
+impl Sphere {
+    pub fn new() -> Self {
+        Sphere {
+            props: ShapeProps::new(),
+        }
+    }
+}
+
+impl Shape for Sphere {
+    fn props(&self) -> &ShapeProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut ShapeProps {
+        &mut self.props
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let sphere_to_ray = ray.origin - Point::new(0.0, 0.0, 0.0);
+
+        let a = ray.direction * ray.direction;
+        let b = 2.0 * (ray.direction * sphere_to_ray);
+        let c = sphere_to_ray * sphere_to_ray - 1.0;
+
+        let discriminant = b.powi(2) - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            return vec![];
+        }
+
+        let t1 = (-b - discriminant.sqrt()) / (2.0 * a);
+        let t2 = (-b + discriminant.sqrt()) / (2.0 * a);
+
+        vec![Intersection::new(t1, self), Intersection::new(t2, self)]
+    }
+
+    fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+        point - Point::new(0.0, 0.0, 0.0)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+pub mod sphere_tests {
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+    use crate::{
+        core::{matrix4::Matrix4, transforms::Transforms},
+        shapes::shape::same_shape,
+    };
+
+    use super::*;
+
+    /// A unit sphere made of glass.
+    pub fn glass_sphere() -> Sphere {
+        let mut s = Sphere::new();
+        let material = s.props_mut().material_mut();
+        material.transparency = 1.0;
+        material.refractive_index = 1.5;
+
+        s
+    }
+
+    #[test]
+    fn ray_intersects_sphere_at_two_points() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 4.0);
+        assert_eq!(xs[1].t, 6.0);
+    }
+
+    #[test]
+    fn ray_intersects_sphere_at_tangent() {
+        let r = Ray::from(Point::new(0.0, 1.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 5.0);
+        assert_eq!(xs[1].t, 5.0);
+    }
+
+    #[test]
+    fn ray_misses_sphere() {
+        let r = Ray::from(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        let xs = s.intersect(&r);
+
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn ray_originates_inside_sphere() {
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, -1.0);
+        assert_eq!(xs[1].t, 1.0);
+    }
+
+    #[test]
+    fn sphere_is_behind_ray() {
+        let r = Ray::from(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, -6.0);
+        assert_eq!(xs[1].t, -4.0);
+    }
+
+    #[test]
+    fn intersect_sets_object_on_intersection() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let s = Sphere::new();
+
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert!(same_shape(xs[0].object, &s));
+        assert!(same_shape(xs[1].object, &s));
+    }
+
+    #[test]
+    fn sphere_default_transform_is_identity() {
+        let s = Sphere::new();
+
+        assert_eq!(*s.transform(), Matrix4::IDENTITY);
+        assert_eq!(*s.props().inverse(), Matrix4::IDENTITY);
+    }
+
+    #[test]
+    fn changing_sphere_transform_updates_cached_inverses() {
+        let mut s = Sphere::new();
+        let t = Transforms::scaling(2.0, 3.0, 4.0) * Transforms::translation(2.0, 3.0, 4.0);
+
+        s.set_transform(t);
+
+        assert_eq!(*s.transform(), t);
+        assert_eq!(*s.props().inverse(), t.inverse());
+        assert_eq!(*s.props().inverse_transpose(), t.inverse().transposed());
+    }
+
+    #[test]
+    fn intersecting_scaled_sphere_with_ray() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(Transforms::scaling(2.0, 2.0, 2.0));
+
+        let xs = s.intersect(&r);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 3.0);
+        assert_eq!(xs[1].t, 7.0);
+    }
+
+    #[test]
+    fn intersecting_translated_sphere_with_ray() {
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let mut s = Sphere::new();
+        s.set_transform(Transforms::translation(5.0, 0.0, 0.0));
+
+        let xs = s.intersect(&r);
+
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn normal_on_sphere_at_points_on_axes() {
+        let s = Sphere::new();
+
+        assert_eq!(
+            s.normal_at(Point::new(1.0, 0.0, 0.0), &Intersection::new(0.0, &s)),
+            Vector::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            s.normal_at(Point::new(0.0, 1.0, 0.0), &Intersection::new(0.0, &s)),
+            Vector::new(0.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            s.normal_at(Point::new(0.0, 0.0, 1.0), &Intersection::new(0.0, &s)),
+            Vector::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn normal_on_sphere_at_nonaxial_point() {
+        let s = Sphere::new();
+        let k = 3.0_f64.sqrt() / 3.0;
+
+        let n = s.normal_at(Point::new(k, k, k), &Intersection::new(0.0, &s));
+
+        assert_eq!(n, Vector::new(k, k, k));
+        assert_eq!(n, n.normalize());
+    }
+
+    #[test]
+    fn normal_on_translated_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(Transforms::translation(0.0, 1.0, 0.0));
+
+        let actual = s.normal_at(
+            Point::new(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+            &Intersection::new(0.0, &s),
+        );
+        let expected = Vector::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn normal_on_transformed_sphere() {
+        let mut s = Sphere::new();
+        s.set_transform(Transforms::scaling(1.0, 0.5, 1.0) * Transforms::rotation_z(PI / 5.0));
+
+        let actual = s.normal_at(
+            Point::new(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0),
+            &Intersection::new(0.0, &s),
+        );
+        let expected = Vector::new(0.0, 0.97014, -0.24254);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sphere_has_bounding_box() {
+        let s = Sphere::new();
+
+        let b = s.bounds();
+
+        assert_eq!(b.min, Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(b.max, Point::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn helper_for_producing_sphere_with_glassy_material() {
+        let s = glass_sphere();
+
+        assert_eq!(*s.transform(), Matrix4::IDENTITY);
+        assert_eq!(s.material().transparency, 1.0);
+        assert_eq!(s.material().refractive_index, 1.5);
+    }
+}
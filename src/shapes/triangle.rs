@@ -0,0 +1,196 @@
+use crate::{
+    core::{point::Point, vector::Vector},
+    intersection::Intersection,
+    ray::Ray,
+    utils::utils::EPSILON,
+};
+
+use super::{
+    bounding_box::BoundingBox,
+    shape::{Shape, ShapeProps},
+};
+
+/// A flat triangle. Its edges and normal are precomputed, since meshes are
+/// made of lots of them.
+#[derive(Debug)]
+pub struct Triangle {
+    props: ShapeProps,
+    pub p1: Point,
+    pub p2: Point,
+    pub p3: Point,
+    pub e1: Vector,
+    pub e2: Vector,
+    pub normal: Vector,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+
+        Triangle {
+            props: ShapeProps::new(),
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal: e2.cross(&e1).normalize(),
+        }
+    }
+}
+
+/// Möller–Trumbore intersection of a ray with the triangle at `p1` spanned by
+/// edges `e1` and `e2`, returning `t` along with the barycentric `u` and `v`.
+pub(crate) fn intersect_triangle(
+    ray: &Ray,
+    p1: Point,
+    e1: Vector,
+    e2: Vector,
+) -> Option<(f64, f64, f64)> {
+    let dir_cross_e2 = ray.direction.cross(&e2);
+    let det = e1 * dir_cross_e2;
+
+    // the ray is parallel to the triangle
+    if det.abs() < EPSILON {
+        return None;
+    }
+
+    let f = 1.0 / det;
+
+    let p1_to_origin = ray.origin - p1;
+    let u = f * (p1_to_origin * dir_cross_e2);
+
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let origin_cross_e1 = p1_to_origin.cross(&e1);
+    let v = f * (ray.direction * origin_cross_e1);
+
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    Some((f * (e2 * origin_cross_e1), u, v))
+}
+
+impl Shape for Triangle {
+    fn props(&self) -> &ShapeProps {
+        &self.props
+    }
+
+    fn props_mut(&mut self) -> &mut ShapeProps {
+        &mut self.props
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        match intersect_triangle(ray, self.p1, self.e1, self.e2) {
+            Some((t, u, v)) => vec![Intersection::with_uv(t, self, u, v)],
+            None => vec![],
+        }
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        self.normal
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+
+        for point in [self.p1, self.p2, self.p3] {
+            bounds.add_point(point);
+        }
+
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod triangle_tests {
+    use super::*;
+
+    fn triangle() -> Triangle {
+        Triangle::new(
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn constructing_triangle() {
+        let t = triangle();
+
+        assert_eq!(t.e1, Vector::new(-1.0, -1.0, 0.0));
+        assert_eq!(t.e2, Vector::new(1.0, -1.0, 0.0));
+        assert_eq!(t.normal, Vector::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn normal_on_triangle_is_constant() {
+        let t = triangle();
+        let i = Intersection::new(0.0, &t);
+
+        assert_eq!(t.local_normal_at(Point::new(0.0, 0.5, 0.0), &i), t.normal);
+        assert_eq!(t.local_normal_at(Point::new(-0.5, 0.75, 0.0), &i), t.normal);
+        assert_eq!(t.local_normal_at(Point::new(0.5, 0.25, 0.0), &i), t.normal);
+    }
+
+    #[test]
+    fn intersecting_ray_parallel_to_triangle() {
+        let t = triangle();
+        let r = Ray::from(Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 1.0, 0.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn ray_misses_p1_p3_edge() {
+        let t = triangle();
+        let r = Ray::from(Point::new(1.0, 1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn ray_misses_p1_p2_edge() {
+        let t = triangle();
+        let r = Ray::from(Point::new(-1.0, 1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn ray_misses_p2_p3_edge() {
+        let t = triangle();
+        let r = Ray::from(Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(t.local_intersect(&r).is_empty());
+    }
+
+    #[test]
+    fn ray_strikes_triangle() {
+        let t = triangle();
+        let r = Ray::from(Point::new(0.0, 0.5, -2.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = t.local_intersect(&r);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 2.0);
+    }
+
+    #[test]
+    fn triangle_has_bounding_box() {
+        let t = Triangle::new(
+            Point::new(-3.0, 7.0, 2.0),
+            Point::new(6.0, 2.0, -4.0),
+            Point::new(2.0, -1.0, -1.0),
+        );
+
+        let b = t.bounds();
+
+        assert_eq!(b.min, Point::new(-3.0, -1.0, -4.0));
+        assert_eq!(b.max, Point::new(6.0, 7.0, 2.0));
+    }
+}
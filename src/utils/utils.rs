@@ -1,3 +1,7 @@
+use std::any::Any;
+#[cfg(test)]
+use std::path::PathBuf;
+
 use float_cmp::F64Margin;
 
 pub const EPSILON: f64 = 1e-5;
@@ -6,3 +10,42 @@ pub const FLOAT_MARGIN: F64Margin = F64Margin {
     epsilon: EPSILON,
     ulps: 4,
 };
+
+/// Advances a splitmix64 generator and returns its next value, a well-spread
+/// scramble of `state` that serves as both random number and hash.
+pub fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Equality between values behind trait objects, so structs holding
+/// patterns or lights can still be compared. Implemented for every
+/// `PartialEq` type.
+pub trait DynEq {
+    fn as_any(&self) -> &dyn Any;
+
+    /// Whether `other` has the same concrete type as `self` and is equal
+    /// to it.
+    fn dyn_eq(&self, other: &dyn Any) -> bool;
+}
+
+impl<T: PartialEq + 'static> DynEq for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<T>() == Some(self)
+    }
+}
+
+/// A path in the temporary directory named after `name` and the running
+/// process, so tests and concurrent test runs never share a file.
+#[cfg(test)]
+pub fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("raytracer_{}_{name}", std::process::id()))
+}
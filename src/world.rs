@@ -0,0 +1,898 @@
+use crate::{
+    core::{color::Color, point::Point, transforms::Transforms, vector::Vector},
+    intersection::{Computations, Intersections},
+    lights::{
+        light::{lighting, Light},
+        point_light::PointLight,
+    },
+    material::Material,
+    ray::Ray,
+    shapes::{shape::Shape, sphere::Sphere},
+};
+
+/// How many times a ray may bounce off reflective surfaces by default.
+pub const DEFAULT_MAX_DEPTH: usize = 5;
+
+/// A scene: every object that can be hit and every light shining on them.
+#[derive(Debug)]
+pub struct World {
+    pub objects: Vec<Box<dyn Shape>>,
+    /// Every light in the scene, of any kind. Their contributions add up,
+    /// each shadowed on its own.
+    pub lights: Vec<Box<dyn Light>>,
+    /// Limit on the number of secondary rays traced from each camera ray,
+    /// so facing mirrors don't recurse forever.
+    pub max_depth: usize,
+}
+
+impl World {
+    pub fn new() -> Self {
+        World {
+            objects: vec![],
+            lights: vec![],
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Two concentric spheres lit by a single white light, handy for tests
+    /// and quick previews.
+    pub fn default_scene() -> Self {
+        let light = PointLight::new(Point::new(-10.0, 10.0, -10.0), Color::WHITE);
+
+        let mut outer = Sphere::new();
+        outer.set_material(Material {
+            diffuse: 0.7,
+            specular: 0.2,
+            ..Material::with_color(Color::new(0.8, 1.0, 0.6))
+        });
+
+        let mut inner = Sphere::new();
+        inner.set_transform(Transforms::scaling(0.5, 0.5, 0.5));
+
+        World {
+            objects: vec![Box::new(outer), Box::new(inner)],
+            lights: vec![Box::new(light)],
+            ..World::new()
+        }
+    }
+
+    pub fn add_object(&mut self, object: impl Shape + 'static) {
+        self.objects.push(Box::new(object));
+    }
+
+    pub fn add_light(&mut self, light: impl Light + 'static) {
+        self.lights.push(Box::new(light));
+    }
+
+    pub fn intersect_world(&self, ray: &Ray) -> Intersections<'_> {
+        let xs = self
+            .objects
+            .iter()
+            .flat_map(|object| object.intersect(ray))
+            .collect();
+
+        Intersections::new(xs)
+    }
+
+    /// Color at a hit, including whatever it reflects and whatever is seen
+    /// through it. `remaining` is the number of bounces still allowed.
+    ///
+    /// The surface is lit by the sum of every light, each scaled by how much
+    /// of it reaches the hit, so a point shadowed from one light can still
+    /// be lit by the others.
+    pub fn shade_hit(&self, comps: &Computations, remaining: usize) -> Color {
+        let material = comps.object.material();
+
+        let surface = self.lights.iter().fold(Color::BLACK, |color, light| {
+            let intensity = self.intensity_at(comps.over_point, light.as_ref());
+
+            color
+                + lighting(
+                    material,
+                    comps.object,
+                    light.as_ref(),
+                    comps.over_point,
+                    comps.eyev,
+                    comps.normalv,
+                    intensity,
+                )
+        });
+
+        let reflected = self.reflected_color(comps, remaining);
+        let refracted = self.refracted_color(comps, remaining);
+
+        if material.reflective > 0.0 && material.transparency > 0.0 {
+            let reflectance = comps.schlick();
+
+            surface + reflected * reflectance + refracted * (1.0 - reflectance)
+        } else {
+            surface + reflected + refracted
+        }
+    }
+
+    /// Color seen along the reflection vector, scaled by how reflective the
+    /// surface is.
+    pub fn reflected_color(&self, comps: &Computations, remaining: usize) -> Color {
+        let reflective = comps.object.material().reflective;
+
+        if remaining == 0 || reflective == 0.0 {
+            return Color::BLACK;
+        }
+
+        let reflect_ray = Ray::from(comps.over_point, comps.reflectv);
+
+        self.color_at_depth(&reflect_ray, remaining - 1) * reflective
+    }
+
+    /// Fraction of `light` reaching `point`, from 0 in full shadow to 1 when
+    /// nothing is in the way. Each sample of the light is tested on its own,
+    /// so points in a penumbra get something in between.
+    ///
+    /// Objects that don't cast shadows are ignored, and a light with shadows
+    /// turned off always reaches the point in full.
+    pub fn intensity_at(&self, point: Point, light: &dyn Light) -> f64 {
+        if !light.casts_shadows() {
+            return 1.0;
+        }
+
+        let samples = light.samples(point);
+        let lit = samples
+            .iter()
+            .filter(|sample| !self.is_occluded(point, sample.lightv, sample.distance))
+            .count();
+
+        lit as f64 / samples.len() as f64
+    }
+
+    /// Whether `light` is completely hidden from `point`.
+    pub fn is_shadowed(&self, point: Point, light: &dyn Light) -> bool {
+        self.intensity_at(point, light) == 0.0
+    }
+
+    /// Whether a shadow casting object is less than `distance` away from
+    /// `point` along `direction`.
+    fn is_occluded(&self, point: Point, direction: Vector, distance: f64) -> bool {
+        let ray = Ray::from(point, direction);
+
+        self.intersect_world(&ray)
+            .iter()
+            .any(|i| i.t >= 0.0 && i.t < distance && i.object.casts_shadow())
+    }
+
+    /// Color seen through the surface along the ray bent by Snell's law,
+    /// scaled by how transparent the surface is.
+    pub fn refracted_color(&self, comps: &Computations, remaining: usize) -> Color {
+        let transparency = comps.object.material().transparency;
+
+        if remaining == 0 || transparency == 0.0 {
+            return Color::BLACK;
+        }
+
+        let n_ratio = comps.n1 / comps.n2;
+        let cos_i = comps.eyev * comps.normalv;
+        let sin2_t = n_ratio.powi(2) * (1.0 - cos_i.powi(2));
+
+        // total internal reflection, nothing gets through
+        if sin2_t > 1.0 {
+            return Color::BLACK;
+        }
+
+        let cos_t = (1.0 - sin2_t).sqrt();
+        let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
+        let refract_ray = Ray::from(comps.under_point, direction);
+
+        self.color_at_depth(&refract_ray, remaining - 1) * transparency
+    }
+
+    pub fn color_at(&self, ray: &Ray) -> Color {
+        self.color_at_depth(ray, self.max_depth)
+    }
+
+    /// Like `color_at`, but allowing only `remaining` more bounces.
+    pub fn color_at_depth(&self, ray: &Ray, remaining: usize) -> Color {
+        let xs = self.intersect_world(ray);
+
+        match xs.hit() {
+            Some(hit) => self.shade_hit(&hit.prepare_computations(ray, &xs), remaining),
+            None => Color::BLACK,
+        }
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        World::new()
+    }
+}
+
+#[cfg(test)]
+mod world_tests {
+    use std::f64::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+
+    use crate::{
+        intersection::Intersection,
+        lights::{
+            area_light::AreaLight, directional_light::DirectionalLight, spot_light::SpotLight,
+        },
+        patterns::pattern::pattern_tests::TestPattern,
+        shapes::{plane::Plane, shape::same_shape},
+        utils::utils::EPSILON,
+    };
+
+    use super::*;
+
+    #[test]
+    fn creating_world() {
+        let w = World::new();
+
+        assert!(w.objects.is_empty());
+        assert!(w.lights.is_empty());
+    }
+
+    #[test]
+    fn default_scene() {
+        let w = World::default_scene();
+
+        let light: &dyn Light = &PointLight::new(Point::new(-10.0, 10.0, -10.0), Color::WHITE);
+
+        assert_eq!(w.lights.len(), 1);
+        assert!(*w.lights[0] == *light);
+        assert_eq!(w.objects.len(), 2);
+        assert_eq!(
+            *w.objects[0].material(),
+            Material {
+                diffuse: 0.7,
+                specular: 0.2,
+                ..Material::with_color(Color::new(0.8, 1.0, 0.6))
+            }
+        );
+        assert_eq!(
+            *w.objects[1].transform(),
+            Transforms::scaling(0.5, 0.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn intersect_world_with_ray() {
+        let w = World::default_scene();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = w.intersect_world(&r);
+
+        assert_eq!(xs.len(), 4);
+        assert_eq!(xs[0].t, 4.0);
+        assert_eq!(xs[1].t, 4.5);
+        assert_eq!(xs[2].t, 5.5);
+        assert_eq!(xs[3].t, 6.0);
+    }
+
+    #[test]
+    fn shading_intersection() {
+        let w = World::default_scene();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = w.objects[0].as_ref();
+        let i = Intersection::new(4.0, shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        let actual = w.shade_hit(&comps, w.max_depth);
+        let expected = Color::new(0.38066, 0.47583, 0.2855);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shading_intersection_from_inside() {
+        let mut w = World::default_scene();
+        w.lights = vec![Box::new(PointLight::new(
+            Point::new(0.0, 0.25, 0.0),
+            Color::WHITE,
+        ))];
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let shape = w.objects[1].as_ref();
+        let i = Intersection::new(0.5, shape);
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        let actual = w.shade_hit(&comps, w.max_depth);
+        let expected = Color::new(0.90498, 0.90498, 0.90498);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn color_when_ray_misses() {
+        let w = World::default_scene();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0));
+
+        assert_eq!(w.color_at(&r), Color::BLACK);
+    }
+
+    #[test]
+    fn color_when_ray_hits() {
+        let w = World::default_scene();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = w.color_at(&r);
+        let expected = Color::new(0.38066, 0.47583, 0.2855);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn color_with_intersection_behind_ray() {
+        let mut w = World::default_scene();
+        w.objects[0].props_mut().material_mut().ambient = 1.0;
+        w.objects[1].props_mut().material_mut().ambient = 1.0;
+        let r = Ray::from(Point::new(0.0, 0.0, 0.75), Vector::new(0.0, 0.0, -1.0));
+
+        let actual = w.color_at(&r);
+        let expected = Color::WHITE;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn intersections_keep_reference_to_world_objects() {
+        let w = World::default_scene();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let xs = w.intersect_world(&r);
+
+        assert!(same_shape(xs[0].object, w.objects[0].as_ref()));
+        assert!(same_shape(xs[1].object, w.objects[1].as_ref()));
+    }
+
+    #[test]
+    fn shading_sums_contribution_of_every_light() {
+        let mut w = World::default_scene();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let single = w.color_at(&r);
+
+        w.add_light(PointLight::new(
+            Point::new(-10.0, 10.0, -10.0),
+            Color::WHITE,
+        ));
+
+        assert_eq!(w.color_at(&r), single * 2.0);
+    }
+
+    #[test]
+    fn shading_with_key_fill_and_rim_lights_adds_each_light() {
+        let key = PointLight::new(Point::new(-10.0, 10.0, -10.0), Color::WHITE);
+        let fill = PointLight {
+            casts_shadows: false,
+            ..PointLight::new(Point::new(10.0, 2.0, -10.0), Color::new(0.3, 0.3, 0.4))
+        };
+        let rim = DirectionalLight::new(Vector::new(0.0, -1.0, 1.0), Color::new(0.5, 0.5, 0.5));
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let lights: [Box<dyn Light>; 3] = [Box::new(key), Box::new(fill), Box::new(rim)];
+
+        let mut w = World::default_scene();
+        let mut sum = Color::BLACK;
+        for light in lights {
+            w.lights = vec![light];
+            sum = sum + w.color_at(&r);
+        }
+
+        let mut w = World::default_scene();
+        w.lights = vec![Box::new(key), Box::new(fill), Box::new(rim)];
+
+        assert_eq!(w.color_at(&r), sum);
+    }
+
+    #[test]
+    fn each_light_has_its_own_shadow_test() {
+        let mut w = World::new();
+        w.add_object(Plane::new());
+        let mut blocker = Sphere::new();
+        blocker.set_transform(Transforms::translation(0.0, 5.0, 0.0));
+        w.add_object(blocker);
+        let blocked = PointLight::new(Point::new(0.0, 10.0, 0.0), Color::WHITE);
+        let lit = PointLight::new(Point::new(10.0, 10.0, 0.0), Color::WHITE);
+        let r = Ray::from(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+
+        w.lights = vec![Box::new(lit)];
+        let only_lit = w.color_at(&r);
+        w.add_light(blocked);
+        let both = w.color_at(&r);
+
+        // the blocked light still adds its ambient term, and nothing else
+        let ambient = Color::WHITE * Material::new().ambient;
+        assert_eq!(both, only_lit + ambient);
+
+        let above_hit = Point::new(0.0, EPSILON, 0.0);
+        assert_eq!(w.intensity_at(above_hit, w.lights[0].as_ref()), 1.0);
+        assert_eq!(w.intensity_at(above_hit, w.lights[1].as_ref()), 0.0);
+    }
+
+    #[test]
+    fn fill_light_without_shadows_reaches_shadowed_point() {
+        let mut w = World::new();
+        w.add_object(Plane::new());
+        let mut blocker = Sphere::new();
+        blocker.set_transform(Transforms::translation(0.0, 5.0, 0.0));
+        w.add_object(blocker);
+        w.add_light(PointLight::new(Point::new(0.0, 10.0, 0.0), Color::WHITE));
+        let r = Ray::from(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+
+        let in_shadow = w.color_at(&r);
+        w.add_light(PointLight {
+            casts_shadows: false,
+            ..PointLight::new(Point::new(0.0, 10.0, 0.0), Color::WHITE)
+        });
+
+        assert!(w.color_at(&r).red > in_shadow.red * 2.0);
+    }
+
+    #[test]
+    fn no_shadow_when_nothing_is_collinear_with_point_and_light() {
+        let w = World::default_scene();
+        let p = Point::new(0.0, 10.0, 0.0);
+
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    #[test]
+    fn shadow_when_object_is_between_point_and_light() {
+        let w = World::default_scene();
+        let p = Point::new(10.0, -10.0, 10.0);
+
+        assert!(w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    #[test]
+    fn no_shadow_when_object_is_behind_light() {
+        let w = World::default_scene();
+        let p = Point::new(-20.0, 20.0, -20.0);
+
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    #[test]
+    fn no_shadow_when_object_is_behind_point() {
+        let w = World::default_scene();
+        let p = Point::new(-2.0, 2.0, -2.0);
+
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    #[test]
+    fn no_shadow_from_objects_that_do_not_cast_shadows() {
+        let mut w = World::default_scene();
+        let p = Point::new(10.0, -10.0, 10.0);
+
+        for object in w.objects.iter_mut() {
+            object.set_casts_shadow(false);
+        }
+
+        assert!(!w.is_shadowed(p, w.lights[0].as_ref()));
+    }
+
+    #[test]
+    fn no_shadow_from_lights_with_shadows_turned_off() {
+        let w = World::default_scene();
+        let p = Point::new(10.0, -10.0, 10.0);
+
+        let light = PointLight {
+            casts_shadows: false,
+            ..PointLight::new(Point::new(-10.0, 10.0, -10.0), Color::WHITE)
+        };
+
+        assert!(!w.is_shadowed(p, &light));
+        assert_eq!(w.intensity_at(p, &light), 1.0);
+    }
+
+    #[test]
+    fn point_lights_evaluate_intensity_at_given_point() {
+        let w = World::default_scene();
+        let light = w.lights[0].as_ref();
+        let cases = [
+            (Point::new(0.0, 1.0001, 0.0), 1.0),
+            (Point::new(-1.0001, 0.0, 0.0), 1.0),
+            (Point::new(0.0, 0.0, -1.0001), 1.0),
+            (Point::new(0.0, 0.0, 1.0001), 0.0),
+            (Point::new(1.0001, 0.0, 0.0), 0.0),
+            (Point::new(0.0, -1.0001, 0.0), 0.0),
+            (Point::new(0.0, 0.0, 0.0), 0.0),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(w.intensity_at(point, light), expected);
+        }
+    }
+
+    #[test]
+    fn area_light_intensity_is_fraction_of_unblocked_samples() {
+        let w = World::default_scene();
+        let light = AreaLight::new(
+            Point::new(-0.5, -0.5, -5.0),
+            Vector::new(1.0, 0.0, 0.0),
+            2,
+            Vector::new(0.0, 1.0, 0.0),
+            2,
+            Color::WHITE,
+        );
+        let cases = [
+            (Point::new(0.0, 0.0, 2.0), 0.0),
+            (Point::new(1.0, -1.0, 2.0), 0.25),
+            (Point::new(1.5, 0.0, 2.0), 0.5),
+            (Point::new(1.25, 1.25, 3.0), 0.75),
+            (Point::new(0.0, 0.0, -2.0), 1.0),
+        ];
+
+        for (point, expected) in cases {
+            assert_eq!(w.intensity_at(point, &light), expected);
+        }
+    }
+
+    #[test]
+    fn area_light_casts_soft_shadow() {
+        let mut w = World::new();
+        w.add_light(AreaLight::new(
+            Point::new(-1.0, 5.0, -1.0),
+            Vector::new(2.0, 0.0, 0.0),
+            4,
+            Vector::new(0.0, 0.0, 2.0),
+            4,
+            Color::WHITE,
+        ));
+        let mut blocker = Sphere::new();
+        blocker.set_transform(
+            Transforms::translation(0.0, 2.0, 0.0) * Transforms::scaling(0.5, 0.5, 0.5),
+        );
+        w.add_object(blocker);
+        let light = w.lights[0].as_ref();
+
+        // under the blocker, at the edge of its shadow and well outside it
+        assert_eq!(w.intensity_at(Point::new(0.0, 0.0, 0.0), light), 0.0);
+        let penumbra = w.intensity_at(Point::new(0.6, 0.0, 0.0), light);
+        assert!(penumbra > 0.0 && penumbra < 1.0);
+        assert_eq!(w.intensity_at(Point::new(5.0, 0.0, 0.0), light), 1.0);
+    }
+
+    #[test]
+    fn directional_light_is_blocked_however_far_away_object_is() {
+        let w = World::default_scene();
+        let light = DirectionalLight::new(Vector::new(0.0, -1.0, 0.0), Color::WHITE);
+
+        assert!(w.is_shadowed(Point::new(0.0, -1000.0, 0.0), &light));
+        assert!(!w.is_shadowed(Point::new(0.0, 1000.0, 0.0), &light));
+        assert!(!w.is_shadowed(Point::new(5.0, -1000.0, 0.0), &light));
+    }
+
+    #[test]
+    fn spot_light_is_blocked_by_objects_in_its_cone() {
+        let w = World::default_scene();
+        let light = SpotLight::new(
+            Point::new(0.0, 10.0, 0.0),
+            Vector::new(0.0, -1.0, 0.0),
+            PI / 8.0,
+            PI / 4.0,
+            Color::WHITE,
+        );
+
+        assert!(w.is_shadowed(Point::new(0.0, -5.0, 0.0), &light));
+        assert!(!w.is_shadowed(Point::new(3.0, -5.0, 0.0), &light));
+    }
+
+    #[test]
+    fn only_ambient_light_outside_spot_light_cone() {
+        let mut w = World::default_scene();
+        w.lights = vec![Box::new(SpotLight::new(
+            Point::new(0.0, 0.0, -10.0),
+            Vector::new(0.0, 1.0, 0.0),
+            PI / 8.0,
+            PI / 4.0,
+            Color::WHITE,
+        ))];
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        // the cone doesn't limit ambient light, which stands in for bounced light
+        let actual = w.color_at(&r);
+        let expected = Color::new(0.8, 1.0, 0.6) * 0.1;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn spot_light_aimed_at_point_shades_like_point_light() {
+        let mut w = World::default_scene();
+        let position = Point::new(-10.0, 10.0, -10.0);
+        w.lights = vec![Box::new(SpotLight::new(
+            position,
+            Point::new(0.0, 0.0, -1.0) - position,
+            PI / 8.0,
+            PI / 4.0,
+            Color::WHITE,
+        ))];
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = w.color_at(&r);
+        let expected = Color::new(0.38066, 0.47583, 0.2855);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shading_with_directional_light() {
+        let mut w = World::default_scene();
+        w.lights = vec![Box::new(DirectionalLight::new(
+            Vector::new(0.0, 0.0, 1.0),
+            Color::WHITE,
+        ))];
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+        // light head on, reflected straight back into the eye
+        let actual = w.color_at(&r);
+        let expected = Color::new(0.8, 1.0, 0.6) * (0.1 + 0.7) + Color::WHITE * 0.2;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shade_hit_is_given_intersection_in_shadow() {
+        let mut w = World::new();
+        w.add_light(PointLight::new(Point::new(0.0, 0.0, -10.0), Color::WHITE));
+        w.add_object(Sphere::new());
+        let mut s2 = Sphere::new();
+        s2.set_transform(Transforms::translation(0.0, 0.0, 10.0));
+        w.add_object(s2);
+        let r = Ray::from(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
+        let i = Intersection::new(4.0, w.objects[1].as_ref());
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        let actual = w.shade_hit(&comps, w.max_depth);
+        let expected = Color::new(0.1, 0.1, 0.1);
+
+        assert_eq!(expected, actual);
+    }
+
+    /// The default scene with a reflective plane below the spheres.
+    fn scene_with_reflective_plane(reflective: f64) -> World {
+        let mut w = World::default_scene();
+        let mut shape = Plane::new();
+        shape.props_mut().material_mut().reflective = reflective;
+        shape.set_transform(Transforms::translation(0.0, -1.0, 0.0));
+        w.add_object(shape);
+
+        w
+    }
+
+    fn ray_towards_plane() -> Ray {
+        Ray::from(
+            Point::new(0.0, 0.0, -3.0),
+            Vector::new(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        )
+    }
+
+    #[test]
+    fn reflected_color_for_nonreflective_material() {
+        let mut w = World::default_scene();
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        w.objects[1].props_mut().material_mut().ambient = 1.0;
+        let i = Intersection::new(1.0, w.objects[1].as_ref());
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert_eq!(w.reflected_color(&comps, w.max_depth), Color::BLACK);
+    }
+
+    #[test]
+    fn reflected_color_for_reflective_material() {
+        let w = scene_with_reflective_plane(0.5);
+        let r = ray_towards_plane();
+        let i = Intersection::new(SQRT_2, w.objects[2].as_ref());
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        let actual = w.reflected_color(&comps, w.max_depth);
+        let expected = Color::new(0.19033, 0.23791, 0.14274);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shade_hit_with_reflective_material() {
+        let w = scene_with_reflective_plane(0.5);
+        let r = ray_towards_plane();
+        let i = Intersection::new(SQRT_2, w.objects[2].as_ref());
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        let actual = w.shade_hit(&comps, w.max_depth);
+        let expected = Color::new(0.87676, 0.92434, 0.82917);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn color_at_with_mutually_reflective_surfaces() {
+        let mut w = World::new();
+        w.add_light(PointLight::new(Point::new(0.0, 0.0, 0.0), Color::WHITE));
+        let mut lower = Plane::new();
+        lower.props_mut().material_mut().reflective = 1.0;
+        lower.set_transform(Transforms::translation(0.0, -1.0, 0.0));
+        w.add_object(lower);
+        let mut upper = Plane::new();
+        upper.props_mut().material_mut().reflective = 1.0;
+        upper.set_transform(Transforms::translation(0.0, 1.0, 0.0));
+        w.add_object(upper);
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+
+        // terminates instead of overflowing the stack
+        let actual = w.color_at(&r);
+
+        assert!(actual.red > 0.0);
+    }
+
+    #[test]
+    fn reflected_color_at_maximum_recursive_depth() {
+        let w = scene_with_reflective_plane(0.5);
+        let r = ray_towards_plane();
+        let i = Intersection::new(SQRT_2, w.objects[2].as_ref());
+        let xs = Intersections::new(vec![i]);
+
+        let comps = i.prepare_computations(&r, &xs);
+
+        assert_eq!(w.reflected_color(&comps, 0), Color::BLACK);
+    }
+
+    #[test]
+    fn max_depth_limits_bounces_between_mirrors() {
+        let mut w = World::new();
+        w.add_light(PointLight::new(Point::new(0.0, 0.0, 0.0), Color::WHITE));
+        for y in [-1.0, 1.0] {
+            let mut mirror = Plane::new();
+            mirror.props_mut().material_mut().reflective = 1.0;
+            mirror.set_transform(Transforms::translation(0.0, y, 0.0));
+            w.add_object(mirror);
+        }
+        let r = Ray::from(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+
+        w.max_depth = 0;
+        let direct = w.color_at(&r);
+        w.max_depth = 1;
+        let one_bounce = w.color_at(&r);
+
+        assert_eq!(one_bounce, direct * 2.0);
+    }
+
+    #[test]
+    fn refracted_color_with_opaque_surface() {
+        let w = World::default_scene();
+        let shape = w.objects[0].as_ref();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(4.0, shape),
+            Intersection::new(6.0, shape),
+        ]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+
+        assert_eq!(w.refracted_color(&comps, 5), Color::BLACK);
+    }
+
+    #[test]
+    fn refracted_color_at_maximum_recursive_depth() {
+        let mut w = World::default_scene();
+        let material = w.objects[0].props_mut().material_mut();
+        material.transparency = 1.0;
+        material.refractive_index = 1.5;
+        let shape = w.objects[0].as_ref();
+        let r = Ray::from(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(4.0, shape),
+            Intersection::new(6.0, shape),
+        ]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+
+        assert_eq!(w.refracted_color(&comps, 0), Color::BLACK);
+    }
+
+    #[test]
+    fn refracted_color_with_refracted_ray() {
+        let mut w = World::default_scene();
+        let a = w.objects[0].props_mut().material_mut();
+        a.ambient = 1.0;
+        a.set_pattern(TestPattern::default());
+        let b = w.objects[1].props_mut().material_mut();
+        b.transparency = 1.0;
+        b.refractive_index = 1.5;
+        let (a, b) = (w.objects[0].as_ref(), w.objects[1].as_ref());
+        let r = Ray::from(Point::new(0.0, 0.0, 0.1), Vector::new(0.0, 1.0, 0.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(-0.9899, a),
+            Intersection::new(-0.4899, b),
+            Intersection::new(0.4899, b),
+            Intersection::new(0.9899, a),
+        ]);
+
+        let comps = xs[2].prepare_computations(&r, &xs);
+
+        let actual = w.refracted_color(&comps, 5);
+        // the book's 0.04725 assumes a larger EPSILON for under_point
+        let expected = Color::new(0.0, 0.99887, 0.04722);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn refracted_color_under_total_internal_reflection() {
+        let mut w = World::default_scene();
+        let material = w.objects[0].props_mut().material_mut();
+        material.transparency = 1.0;
+        material.refractive_index = 1.5;
+        let shape = w.objects[0].as_ref();
+        let r = Ray::from(
+            Point::new(0.0, 0.0, FRAC_1_SQRT_2),
+            Vector::new(0.0, 1.0, 0.0),
+        );
+        let xs = Intersections::new(vec![
+            Intersection::new(-FRAC_1_SQRT_2, shape),
+            Intersection::new(FRAC_1_SQRT_2, shape),
+        ]);
+
+        // inside the sphere, so the second intersection is the one to look at
+        let comps = xs[1].prepare_computations(&r, &xs);
+
+        assert_eq!(w.refracted_color(&comps, 5), Color::BLACK);
+    }
+
+    /// The default scene above a glass floor, with a red ball underneath.
+    fn scene_with_glass_floor(reflective: f64) -> World {
+        let mut w = World::default_scene();
+        let mut floor = Plane::new();
+        floor.set_transform(Transforms::translation(0.0, -1.0, 0.0));
+        let material = floor.props_mut().material_mut();
+        material.reflective = reflective;
+        material.transparency = 0.5;
+        material.refractive_index = 1.5;
+        w.add_object(floor);
+        let mut ball = Sphere::new();
+        ball.set_transform(Transforms::translation(0.0, -3.5, -0.5));
+        let material = ball.props_mut().material_mut();
+        material.set_color(Color::new(1.0, 0.0, 0.0));
+        material.ambient = 0.5;
+        w.add_object(ball);
+
+        w
+    }
+
+    #[test]
+    fn shade_hit_with_transparent_material() {
+        let w = scene_with_glass_floor(0.0);
+        let r = ray_towards_plane();
+        let xs = Intersections::new(vec![Intersection::new(SQRT_2, w.objects[2].as_ref())]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+
+        let actual = w.shade_hit(&comps, 5);
+        let expected = Color::new(0.93642, 0.68642, 0.68642);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn shade_hit_with_reflective_transparent_material() {
+        let w = scene_with_glass_floor(0.5);
+        let r = ray_towards_plane();
+        let xs = Intersections::new(vec![Intersection::new(SQRT_2, w.objects[2].as_ref())]);
+
+        let comps = xs[0].prepare_computations(&r, &xs);
+
+        let actual = w.shade_hit(&comps, 5);
+        let expected = Color::new(0.93391, 0.69643, 0.69243);
+
+        assert_eq!(expected, actual);
+    }
+}